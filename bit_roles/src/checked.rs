@@ -1,16 +1,65 @@
-use crate::RoleVariant;
+use crate::{
+    is_valid_role,
+    RoleDiff,
+    RoleError,
+    RoleManagerUnchecked,
+    RoleValue,
+    RoleVariant,
+};
 use std::{
+    borrow::Borrow,
+    collections::BTreeSet,
     marker::PhantomData,
     ops::{
         BitAnd,
         BitAndAssign,
         BitOrAssign,
+        BitXorAssign,
+        Not,
     },
 };
 
 /// Bit role trait with compile-time value checks. Implements [RoleManager] for
 /// a role enum.
 pub trait BitRoleImpl<T> {
+    /// The mask of every declared role, i.e. the bitwise OR of all the
+    /// variant discriminants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// assert_eq!(MyRole::DECLARED_MASK, 3);
+    /// ```
+    const DECLARED_MASK: usize;
+
+    /// The number of bits spanned by the declared roles, i.e. the position of
+    /// the highest declared bit plus one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Admin = 8,
+    /// }
+    ///
+    /// assert_eq!(MyRole::DECLARED_BITS, 4);
+    /// ```
+    const DECLARED_BITS: usize = (usize::BITS - Self::DECLARED_MASK.leading_zeros()) as usize;
+
     /// Creates a new [RoleManager] instance with the default value.
     ///
     /// # Examples
@@ -32,6 +81,10 @@ pub trait BitRoleImpl<T> {
     fn empty() -> RoleManager<T>;
     /// Creates a new [RoleManager] instance with the provided value.
     ///
+    /// When the `strict` feature is enabled, this panics if the value contains
+    /// bits that do not belong to any declared role. This guards against
+    /// loading corrupt values at the cost of a check on every construction.
+    ///
     /// # Examples
     ///
     /// ```
@@ -44,13 +97,142 @@ pub trait BitRoleImpl<T> {
     /// }
     ///
     /// // Create a manager instance with the provided value.
-    /// let mut roles = MyRole::from_value(5);
+    /// let mut roles = MyRole::from_value(1);
     ///
-    /// assert_eq!(roles.get_value(), 5);
+    /// assert_eq!(roles.get_value(), 1);
     /// ```
     ///
     /// * `value` - The value for the manager.
-    fn from_value(value: usize) -> RoleManager<T>;
+    fn from_value(value: usize) -> RoleManager<T> {
+        #[cfg(feature = "strict")]
+        assert!(
+            value & !Self::DECLARED_MASK == 0,
+            "`value` contains undeclared roles"
+        );
+
+        RoleManager(value, PhantomData)
+    }
+    /// Creates a new [RoleManager] instance with every declared role.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// // Create a manager instance with every declared role.
+    /// let roles = MyRole::all();
+    ///
+    /// assert_eq!(roles.get_value(), 3);
+    /// ```
+    fn all() -> RoleManager<T> {
+        RoleManager(Self::DECLARED_MASK, PhantomData)
+    }
+    /// Returns every declared role, excluding the zero variant, in the order
+    /// of declaration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let roles = MyRole::all_roles();
+    ///
+    /// assert!(matches!(roles.as_slice(), [MyRole::Staff, MyRole::Member]));
+    /// ```
+    fn all_roles() -> Vec<T>;
+    /// Returns the name of the role variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    /// }
+    ///
+    /// assert_eq!(MyRole::Staff.name(), "Staff");
+    /// ```
+    fn name(self) -> &'static str;
+    /// Returns the role variant with the provided name, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    /// }
+    ///
+    /// assert!(matches!(MyRole::from_name("Staff"), Some(MyRole::Staff)));
+    /// assert!(MyRole::from_name("Guest").is_none());
+    /// ```
+    ///
+    /// * `name` - The name of the role variant.
+    fn from_name(name: &str) -> Option<T>;
+    /// Creates a new [RoleManager] instance from the names of role variants.
+    /// Returns an error listing every unknown name if any of the names does
+    /// not match a role variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let roles = MyRole::from_names(&["Staff", "Member"]).expect("unknown names");
+    ///
+    /// assert!(roles.has_all(vec![MyRole::Staff, MyRole::Member]));
+    /// ```
+    ///
+    /// * `names` - The names of the role variants.
+    fn from_names(names: &[&str]) -> Result<RoleManager<T>, RoleError>
+    where
+        T: RoleVariant + BitRoleImpl<T>,
+    {
+        let mut manager = Self::empty();
+        let mut unknown = Vec::new();
+
+        for name in names {
+            match Self::from_name(name) {
+                Some(role) => {
+                    manager.add_one(role);
+                }
+                None => unknown.push(name.to_string()),
+            }
+        }
+
+        if unknown.is_empty() {
+            Ok(manager)
+        } else {
+            Err(RoleError::UnknownNames(unknown))
+        }
+    }
 }
 
 /// The default role manager with compile-time value checks.
@@ -61,12 +243,17 @@ impl<T> RoleManager<T>
 where
     T: RoleVariant,
 {
-    /// Adds a single role to the manager instance.
+    /// Creates a new [RoleManager] instance with the provided value without
+    /// checking it against the declared roles, even when the `strict` feature
+    /// is enabled. Useful when the value has already been validated.
     ///
     /// # Examples
     ///
     /// ```
-    /// use bit_roles::BitRole;
+    /// use bit_roles::{
+    ///     BitRole,
+    ///     RoleManager,
+    /// };
     ///
     /// #[derive(Debug, BitRole, Copy, Clone)]
     /// enum MyRole {
@@ -74,26 +261,26 @@ where
     ///     Staff = 1,
     /// }
     ///
-    /// let mut roles = MyRole::empty();
-    ///
-    /// // Add a new role to the manager.
-    /// roles.add_one(MyRole::Staff);
+    /// let roles = RoleManager::<MyRole>::from_value_unchecked(5);
     ///
-    /// assert!(roles.has_one(MyRole::Staff));
+    /// assert_eq!(roles.get_value(), 5);
     /// ```
     ///
-    /// * `role` - The role to add to the manager.
-    pub fn add_one(&mut self, role: T) -> &mut Self {
-        self.0.bitor_assign(role.into());
-        self
+    /// * `value` - The value for the manager.
+    pub fn from_value_unchecked(value: usize) -> Self {
+        RoleManager(value, PhantomData)
     }
 
-    /// Adds multiple roles to the manager instance.
+    /// Creates a new [RoleManager] instance holding every role assigned to any
+    /// of the provided managers.
     ///
     /// # Examples
     ///
     /// ```
-    /// use bit_roles::BitRole;
+    /// use bit_roles::{
+    ///     BitRole,
+    ///     RoleManager,
+    /// };
     ///
     /// #[derive(Debug, BitRole, Copy, Clone)]
     /// enum MyRole {
@@ -102,24 +289,26 @@ where
     ///     Member = 2,
     /// }
     ///
-    /// let mut roles = MyRole::empty();
+    /// let staff = MyRole::from_value(MyRole::Staff.into());
+    /// let member = MyRole::from_value(MyRole::Member.into());
     ///
-    /// // Add multiple new roles to the manager.
-    /// roles.add_all(vec![MyRole::Staff, MyRole::Member]);
+    /// // Combine the roles of both managers.
+    /// let roles = RoleManager::combine(&[staff, member]);
     ///
     /// assert!(roles.has_all(vec![MyRole::Staff, MyRole::Member]));
     /// ```
     ///
-    /// * `roles` - The roles to add to the manager.
-    pub fn add_all(&mut self, roles: Vec<T>) -> &mut Self {
-        roles.into_iter().for_each(|role| {
-            self.add_one(role);
-        });
-
-        self
+    /// * `managers` - The managers to combine.
+    pub fn combine(managers: &[Self]) -> Self {
+        RoleManager(
+            managers.iter().fold(0, |value, manager| value | manager.0),
+            PhantomData,
+        )
     }
 
-    /// Removes a single role from the manager instance.
+    /// Retains only the bits of a raw integer mask in the manager instance,
+    /// clearing every other bit. The mask is not validated against the role
+    /// enum.
     ///
     /// # Examples
     ///
@@ -130,23 +319,26 @@ where
     /// enum MyRole {
     ///     None = 0,
     ///     Staff = 1,
+    ///     Member = 2,
+    ///     Guest = 4,
     /// }
     ///
-    /// let mut roles = MyRole::empty();
+    /// let mut roles = MyRole::from_value(7);
     ///
-    /// // Remove a role from the manager.
-    /// roles.remove_one(MyRole::Staff);
+    /// // Keep only the `Staff` and `Member` roles.
+    /// roles.retain_value(3);
     ///
-    /// assert!(roles.not_one(MyRole::Staff));
+    /// assert_eq!(roles.get_value(), 3);
     /// ```
     ///
-    /// * `role` - The role to remove from the manager.
-    pub fn remove_one(&mut self, role: T) -> &mut Self {
-        self.0.bitand_assign(!role.into());
+    /// * `mask` - The mask of the bits to keep.
+    pub fn retain_value(&mut self, mask: usize) -> &mut Self {
+        self.0.bitand_assign(mask);
         self
     }
 
-    /// Removes multiple roles from the manager instance.
+    /// Toggles the bits of a raw integer mask in the manager instance. The
+    /// mask is not validated against the role enum.
     ///
     /// # Examples
     ///
@@ -160,20 +352,49 @@ where
     ///     Member = 2,
     /// }
     ///
-    /// let mut roles = MyRole::empty();
+    /// let mut roles = MyRole::from_value(MyRole::Staff.into());
     ///
-    /// // Remove multiple roles from the manager.
-    /// roles.remove_all(vec![MyRole::Staff, MyRole::Member]);
+    /// // Toggle the `Staff` and `Member` roles.
+    /// roles.toggle_value(3);
     ///
-    /// assert!(roles.not_all(vec![MyRole::Staff, MyRole::Member]));
+    /// assert_eq!(roles.get_value(), 2);
     /// ```
     ///
-    /// * `roles` - The roles to remove from the manager.
-    pub fn remove_all(&mut self, roles: Vec<T>) -> &mut Self {
-        roles.into_iter().for_each(|role| {
-            self.remove_one(role);
-        });
+    /// * `mask` - The mask of the bits to toggle.
+    pub fn toggle_value(&mut self, mask: usize) -> &mut Self {
+        self.0.bitxor_assign(mask);
+        self
+    }
 
+    /// Removes the bits of a raw integer value from the manager instance. The
+    /// value is not validated against the role enum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRole,
+    ///     RoleManager,
+    /// };
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    /// }
+    ///
+    /// // Create a role manager from a stored value with an undeclared bit.
+    /// let mut roles = RoleManager::<MyRole>::from_value_unchecked(5);
+    ///
+    /// // Remove the undeclared bit from the manager.
+    /// roles.remove_value(4);
+    ///
+    /// assert_eq!(roles.get_value(), 1);
+    /// ```
+    ///
+    /// * `value` - The value to remove from the manager.
+    pub fn remove_value(&mut self, value: usize) -> &mut Self {
+        self.0.bitand_assign(!value);
         self
     }
 
@@ -200,8 +421,70 @@ where
     /// ```
     ///
     /// * `role` - The role to check against the manager.
+    #[must_use]
     pub fn has_one(&self, role: T) -> bool {
-        self.0.bitand(role.into()) != 0
+        self.0.bitand(role.value()) != 0
+    }
+
+    /// Checks whether any bit of a raw integer value is assigned to the manager
+    /// instance. The value is not validated against the role enum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRole,
+    ///     RoleManager,
+    /// };
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    /// }
+    ///
+    /// // Create a role manager from a stored value with an undeclared bit.
+    /// let roles = RoleManager::<MyRole>::from_value_unchecked(5);
+    ///
+    /// // Check if the manager has the undeclared bit.
+    /// let has_value = roles.has_value(4);
+    ///
+    /// assert!(has_value);
+    /// ```
+    ///
+    /// * `value` - The value to check against the manager.
+    #[must_use]
+    pub fn has_value(&self, value: usize) -> bool {
+        self.0.bitand(value) != 0
+    }
+
+    /// Checks whether a role value is assigned to the manager instance. Raw
+    /// values are not validated against the role enum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRole,
+    ///     RoleValue,
+    /// };
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(MyRole::Staff.into());
+    ///
+    /// assert!(roles.has_value_of(RoleValue::Role(MyRole::Staff)));
+    /// assert!(roles.has_value_of(RoleValue::Raw(1)));
+    /// ```
+    ///
+    /// * `role` - The role value to check against the manager.
+    #[must_use]
+    pub fn has_value_of(&self, role: RoleValue<T>) -> bool {
+        self.has_value(role.into())
     }
 
     /// Checks whether each of the roles is assigned to the manager instance.
@@ -230,10 +513,11 @@ where
     /// ```
     ///
     /// * `roles` - The roles to check against the manager.
+    #[must_use]
     pub fn has_all(&self, roles: Vec<T>) -> bool {
         roles
             .into_iter()
-            .all(|role| self.0.bitand(Into::<usize>::into(role)) != 0)
+            .all(|role| self.0.bitand(role.value()) != 0)
     }
 
     /// Checks whether any one of the roles is assigned to the manager instance.
@@ -262,10 +546,11 @@ where
     /// ```
     ///
     /// * `roles` - The roles to check against the manager.
+    #[must_use]
     pub fn has_any(&self, roles: Vec<T>) -> bool {
         roles
             .into_iter()
-            .any(|role| self.0.bitand(Into::<usize>::into(role)) != 0)
+            .any(|role| self.0.bitand(role.value()) != 0)
     }
 
     /// Checks whether a single role is not assigned to the manager instance.
@@ -290,6 +575,7 @@ where
     /// ```
     ///
     /// * `role` - The role to check against the manager.
+    #[must_use]
     pub fn not_one(&self, role: T) -> bool {
         !self.has_one(role)
     }
@@ -318,6 +604,7 @@ where
     /// ```
     ///
     /// * `roles` - The roles to check against the manager.
+    #[must_use]
     pub fn not_all(&self, roles: Vec<T>) -> bool {
         !self.has_all(roles)
     }
@@ -345,11 +632,13 @@ where
     /// ```
     ///
     /// * `roles` - The roles to check against the manager.
+    #[must_use]
     pub fn not_any(&self, roles: Vec<T>) -> bool {
         !self.has_any(roles)
     }
 
-    /// Returns the value of the manager instance.
+    /// Checks whether every role assigned to the manager instance is within
+    /// the allowed roles.
     ///
     /// # Examples
     ///
@@ -360,24 +649,2617 @@ where
     /// enum MyRole {
     ///     None = 0,
     ///     Staff = 1,
+    ///     Member = 2,
     /// }
     ///
-    /// let roles = MyRole::empty();
+    /// let mut roles = MyRole::empty();
+    /// roles.add_one(MyRole::Member);
     ///
-    /// // Get value of the manager instance.
-    /// let value = roles.get_value();
+    /// // Check if the manager only has the allowed roles.
+    /// let has_only = roles.has_only(&[MyRole::Member]);
     ///
-    /// assert_eq!(value, 0);
+    /// assert!(has_only);
     /// ```
-    pub fn get_value(&self) -> usize {
-        self.0
+    ///
+    /// * `allowed` - The roles the manager is allowed to have.
+    #[must_use]
+    pub fn has_only(&self, allowed: &[T]) -> bool {
+        let allowed_mask = allowed
+            .iter()
+            .fold(0, |mask, role| mask | role.value());
+
+        self.0 & !allowed_mask == 0
     }
-}
 
-impl<T> PartialEq<Self> for RoleManager<T> {
-    fn eq(&self, other: &Self) -> bool {
-        self.0 == other.0
+    /// Returns the number of required roles that are not assigned to the
+    /// manager instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(MyRole::Staff.into());
+    ///
+    /// // Count the required roles missing from the manager.
+    /// let missing = roles.count_missing(&[MyRole::Staff, MyRole::Member]);
+    ///
+    /// assert_eq!(missing, 1);
+    /// ```
+    ///
+    /// * `required` - The roles to check against the manager.
+    #[must_use]
+    pub fn count_missing(&self, required: &[T]) -> usize {
+        required.iter().filter(|role| self.not_one(**role)).count()
     }
-}
 
-impl<T> Eq for RoleManager<T> {}
+    /// Checks whether the manager instance holds every role assigned to the
+    /// other manager.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let mut roles = MyRole::empty();
+    /// roles.add_all(vec![MyRole::Staff, MyRole::Member]);
+    ///
+    /// let required = MyRole::from_value(MyRole::Staff.into());
+    ///
+    /// assert!(roles.is_superset_of(&required));
+    /// ```
+    ///
+    /// * `other` - The manager to compare against.
+    #[must_use]
+    pub fn is_superset_of(&self, other: &Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Checks whether every role assigned to the manager instance is also
+    /// assigned to the other manager.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(MyRole::Staff.into());
+    ///
+    /// let mut allowed = MyRole::empty();
+    /// allowed.add_all(vec![MyRole::Staff, MyRole::Member]);
+    ///
+    /// assert!(roles.is_subset_of(&allowed));
+    /// ```
+    ///
+    /// * `other` - The manager to compare against.
+    #[must_use]
+    pub fn is_subset_of(&self, other: &Self) -> bool {
+        self.0 & other.0 == self.0
+    }
+
+    /// Checks whether the manager instance satisfies the provided predicate.
+    /// See [all_of], [any_of], and [none_of] for composable predicates.
+    ///
+    /// [all_of]: crate::all_of
+    /// [any_of]: crate::any_of
+    /// [none_of]: crate::none_of
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(MyRole::Staff.into());
+    ///
+    /// // Check the manager against a custom predicate.
+    /// let is_staff = roles.satisfies(|roles| roles.has_one(MyRole::Staff));
+    ///
+    /// assert!(is_staff);
+    /// ```
+    ///
+    /// * `pred` - The predicate to check the manager against.
+    #[must_use]
+    pub fn satisfies<F>(&self, pred: F) -> bool
+    where
+        F: Fn(&RoleManager<T>) -> bool,
+    {
+        pred(self)
+    }
+
+    /// Returns the value of the manager instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    /// }
+    ///
+    /// let roles = MyRole::empty();
+    ///
+    /// // Get value of the manager instance.
+    /// let value = roles.get_value();
+    ///
+    /// assert_eq!(value, 0);
+    /// ```
+    #[must_use]
+    pub fn get_value(&self) -> usize {
+        self.0
+    }
+
+    /// Returns the raw XOR of the values of two manager instances, i.e. the
+    /// bits that differ between them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let previous = MyRole::from_value(MyRole::Staff.into());
+    /// let current = MyRole::from_value(MyRole::Member.into());
+    ///
+    /// // Get the bits that differ between the two managers.
+    /// let diff = previous.diff_value(&current);
+    ///
+    /// assert_eq!(diff, 3);
+    /// ```
+    ///
+    /// * `other` - The manager to compare against.
+    #[must_use]
+    pub fn diff_value(&self, other: &Self) -> usize {
+        self.0 ^ other.0
+    }
+
+    /// Applies a delta previously computed with [diff_value] to the manager
+    /// instance.
+    ///
+    /// [diff_value]: RoleManager::diff_value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let mut roles = MyRole::from_value(MyRole::Staff.into());
+    /// let current = MyRole::from_value(MyRole::Member.into());
+    /// let diff = roles.diff_value(&current);
+    ///
+    /// // Replay the delta onto the previous state.
+    /// roles.apply_diff(diff);
+    ///
+    /// assert_eq!(roles, current);
+    /// ```
+    ///
+    /// * `diff` - The delta to apply to the manager.
+    pub fn apply_diff(&mut self, diff: usize) -> &mut Self {
+        self.0.bitxor_assign(diff);
+        self
+    }
+
+    /// Returns a new [RoleManager] instance holding only the roles within the
+    /// provided mask.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    ///     Guest = 4,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(7);
+    ///
+    /// // Extract the `Staff` and `Member` roles.
+    /// let masked = roles.masked(3);
+    ///
+    /// assert_eq!(masked.get_value(), 3);
+    /// ```
+    ///
+    /// * `mask` - The mask of the roles to keep.
+    #[must_use]
+    pub fn masked(&self, mask: usize) -> Self {
+        RoleManager(self.0 & mask, PhantomData)
+    }
+
+    /// Returns an iterator over the positions of the bits set in the manager
+    /// instance, from the least significant bit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    ///     Guest = 4,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(5);
+    ///
+    /// assert_eq!(roles.bits().collect::<Vec<_>>(), vec![0, 2]);
+    /// ```
+    pub fn bits(&self) -> impl Iterator<Item = u32> {
+        let mut value = self.0;
+
+        std::iter::from_fn(move || {
+            (value != 0).then(|| {
+                let position = value.trailing_zeros();
+                value &= value - 1;
+                position
+            })
+        })
+    }
+
+    /// Returns the value of the manager instance as a `u32`. Returns an
+    /// error if the value does not fit in 32 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(MyRole::Staff.into());
+    ///
+    /// assert_eq!(roles.try_as_u32().expect("value overflow"), 1);
+    /// ```
+    pub fn try_as_u32(&self) -> Result<u32, RoleError> {
+        u32::try_from(self.0).map_err(|_| RoleError::Overflow(self.0))
+    }
+
+    /// Returns the value of the manager instance as a `u64`. Returns an
+    /// error if the value does not fit in 64 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(MyRole::Staff.into());
+    ///
+    /// assert_eq!(roles.try_as_u64().expect("value overflow"), 1);
+    /// ```
+    pub fn try_as_u64(&self) -> Result<u64, RoleError> {
+        u64::try_from(self.0).map_err(|_| RoleError::Overflow(self.0))
+    }
+
+    /// Replaces the value of the manager instance with the value of the other
+    /// manager.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    /// }
+    ///
+    /// let baseline = MyRole::from_value(MyRole::Staff.into());
+    /// let mut roles = MyRole::empty();
+    ///
+    /// // Reset the manager to the baseline.
+    /// roles.assign(&baseline);
+    ///
+    /// assert_eq!(roles, baseline);
+    /// ```
+    ///
+    /// * `other` - The manager to copy the value from.
+    pub fn assign(&mut self, other: &Self) -> &mut Self {
+        self.0 = other.0;
+        self
+    }
+
+    /// Returns the manager instance with its value replaced by a raw integer
+    /// value. The value is not validated against the role enum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let roles = MyRole::empty().with_value(MyRole::Member.into());
+    ///
+    /// assert!(roles.has_one(MyRole::Member));
+    /// ```
+    ///
+    /// * `value` - The value to replace the manager value with.
+    #[must_use]
+    pub fn with_value(mut self, value: usize) -> Self {
+        self.0 = value;
+        self
+    }
+
+    /// Narrows the manager instance to the roles also assigned to the other
+    /// manager.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    ///     Guest = 4,
+    /// }
+    ///
+    /// let mut roles = MyRole::from_value(7);
+    /// let allowed = MyRole::from_value(MyRole::Member.into());
+    ///
+    /// roles.intersect_with(&allowed);
+    ///
+    /// assert_eq!(roles.get_value(), MyRole::Member.into());
+    /// ```
+    ///
+    /// * `other` - The manager to intersect with.
+    pub fn intersect_with(&mut self, other: &Self) -> &mut Self {
+        self.0.bitand_assign(other.0);
+        self
+    }
+
+    /// Adds every role assigned to the other manager to the manager instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    ///     Guest = 4,
+    /// }
+    ///
+    /// let mut roles = MyRole::from_value(MyRole::Staff.into());
+    /// let granted = MyRole::from_value(6);
+    ///
+    /// roles.union_with(&granted);
+    ///
+    /// assert_eq!(roles.get_value(), 7);
+    /// ```
+    ///
+    /// * `other` - The manager to add the roles from.
+    pub fn union_with(&mut self, other: &Self) -> &mut Self {
+        self.0.bitor_assign(other.0);
+        self
+    }
+
+    /// Removes every role assigned to the other manager from the manager
+    /// instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    ///     Guest = 4,
+    /// }
+    ///
+    /// let mut roles = MyRole::from_value(7);
+    /// let revoked = MyRole::from_value(6);
+    ///
+    /// roles.subtract(&revoked);
+    ///
+    /// assert_eq!(roles.get_value(), MyRole::Staff.into());
+    /// ```
+    ///
+    /// * `other` - The manager to remove the roles of.
+    pub fn subtract(&mut self, other: &Self) -> &mut Self {
+        self.0.bitand_assign(!other.0);
+        self
+    }
+
+    /// Returns the value of the manager instance as a fixed-width, 64-bit
+    /// little-endian byte array, independent of the platform `usize` width.
+    /// Fails to compile on targets where `usize` is wider than 64 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(3);
+    ///
+    /// assert_eq!(roles.to_le_bytes(), [3, 0, 0, 0, 0, 0, 0, 0]);
+    /// ```
+    #[must_use]
+    pub fn to_le_bytes(&self) -> [u8; 8] {
+        const _: () = assert!(usize::BITS <= u64::BITS, "`usize` is wider than 64 bits");
+        (self.0 as u64).to_le_bytes()
+    }
+
+    /// Creates a new [RoleManager] instance from a 64-bit little-endian byte
+    /// array produced by [RoleManager::to_le_bytes]. The value is not
+    /// validated against the declared roles, so that any value produced by
+    /// [RoleManager::to_le_bytes] round-trips. Use
+    /// [RoleManager::checked_from_value] to reject undeclared roles.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value does not fit in the platform `usize` width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRole,
+    ///     RoleManager,
+    /// };
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(3);
+    /// let bytes = roles.to_le_bytes();
+    ///
+    /// assert_eq!(RoleManager::<MyRole>::from_le_bytes(bytes), roles);
+    /// ```
+    ///
+    /// * `bytes` - The little-endian bytes of the value.
+    #[must_use]
+    pub fn from_le_bytes(bytes: [u8; 8]) -> Self {
+        RoleManager(
+            usize::try_from(u64::from_le_bytes(bytes))
+                .expect("`bytes` hold a value beyond the width of `usize`"),
+            PhantomData,
+        )
+    }
+
+    /// Returns an error listing the values of the required roles that are not
+    /// assigned to the manager instance, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRole,
+    ///     RoleError,
+    /// };
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(MyRole::Staff.into());
+    ///
+    /// assert!(roles.require_all(&[MyRole::Staff]).is_ok());
+    /// assert!(matches!(
+    ///     roles.require_all(&[MyRole::Staff, MyRole::Member]),
+    ///     Err(RoleError::Missing(missing)) if missing == vec![2]
+    /// ));
+    /// ```
+    ///
+    /// * `roles` - The roles that must be assigned.
+    pub fn require_all(&self, roles: &[T]) -> Result<(), RoleError> {
+        let missing = roles
+            .iter()
+            .filter(|role| self.not_one(**role))
+            .map(|role| role.value())
+            .collect::<Vec<_>>();
+
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(RoleError::Missing(missing))
+        }
+    }
+
+    /// Returns an error listing the values of the roles if none of them are
+    /// assigned to the manager instance. An empty list of roles is never
+    /// satisfied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRole,
+    ///     RoleError,
+    /// };
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Admin = 1,
+    ///     Owner = 2,
+    ///     Member = 4,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(MyRole::Owner.into());
+    ///
+    /// assert!(roles.require_any(&[MyRole::Admin, MyRole::Owner]).is_ok());
+    /// assert!(matches!(
+    ///     roles.require_any(&[MyRole::Admin, MyRole::Member]),
+    ///     Err(RoleError::Missing(missing)) if missing == vec![1, 4]
+    /// ));
+    /// ```
+    ///
+    /// * `roles` - The roles of which at least one must be assigned.
+    pub fn require_any(&self, roles: &[T]) -> Result<(), RoleError> {
+        if roles.iter().any(|role| self.has_one(*role)) {
+            Ok(())
+        } else {
+            Err(RoleError::Missing(
+                roles.iter().map(|role| role.value()).collect(),
+            ))
+        }
+    }
+
+    /// Returns the first of the required roles that is not assigned to the
+    /// manager instance, or `None` if all of them are assigned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(MyRole::Staff.into());
+    ///
+    /// assert!(matches!(
+    ///     roles.first_missing(&[MyRole::Staff, MyRole::Member]),
+    ///     Some(MyRole::Member)
+    /// ));
+    /// ```
+    ///
+    /// * `required` - The roles that must be assigned.
+    #[must_use]
+    pub fn first_missing(&self, required: &[T]) -> Option<T> {
+        required.iter().copied().find(|role| self.not_one(*role))
+    }
+
+    /// Returns the number of roles assigned to the manager instance within a
+    /// raw integer mask, such as a group mask.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     #[bit_role(group = "content")]
+    ///     SendMessage = 1,
+    ///     #[bit_role(group = "content")]
+    ///     EditMessage = 2,
+    ///     BanMember = 4,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(5);
+    ///
+    /// assert_eq!(roles.count_in(MyRole::CONTENT_MASK), 1);
+    /// ```
+    ///
+    /// * `mask` - The mask to count the roles in.
+    #[must_use]
+    pub fn count_in(&self, mask: usize) -> u32 {
+        self.0.bitand(mask).count_ones()
+    }
+
+    /// Captures the value of the manager instance so that it can be rolled
+    /// back using the [restore] method.
+    ///
+    /// [restore]: RoleManager::restore
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let mut roles = MyRole::from_value(MyRole::Staff.into());
+    /// let snapshot = roles.snapshot();
+    ///
+    /// roles.add_one(MyRole::Member);
+    ///
+    /// // Cancel the edit.
+    /// roles.restore(snapshot);
+    ///
+    /// assert_eq!(roles.get_value(), MyRole::Staff.into());
+    /// ```
+    #[must_use]
+    pub fn snapshot(&self) -> usize {
+        self.0
+    }
+
+    /// Rolls the manager instance back to a value captured using the
+    /// [snapshot] method.
+    ///
+    /// [snapshot]: RoleManager::snapshot
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    /// }
+    ///
+    /// let mut roles = MyRole::empty();
+    /// let snapshot = roles.snapshot();
+    ///
+    /// roles.add_one(MyRole::Staff);
+    /// roles.restore(snapshot);
+    ///
+    /// assert!(roles.not_one(MyRole::Staff));
+    /// ```
+    ///
+    /// * `snapshot` - The captured value to restore.
+    pub fn restore(&mut self, snapshot: usize) -> &mut Self {
+        self.0 = snapshot;
+        self
+    }
+
+    /// Adds every role assigned to any of the provided managers to the manager
+    /// instance. This is the lazy, in-place equivalent of the [combine] method.
+    ///
+    /// [combine]: RoleManager::combine
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let groups = vec![
+    ///     MyRole::from_value(MyRole::Staff.into()),
+    ///     MyRole::from_value(MyRole::Member.into()),
+    /// ];
+    ///
+    /// let mut roles = MyRole::empty();
+    /// roles.merge_iter(groups);
+    ///
+    /// assert!(roles.has_all(vec![MyRole::Staff, MyRole::Member]));
+    /// ```
+    ///
+    /// * `managers` - The managers to merge into the manager instance.
+    pub fn merge_iter(&mut self, managers: impl IntoIterator<Item = Self>) -> &mut Self {
+        for manager in managers {
+            self.0.bitor_assign(manager.0);
+        }
+
+        self
+    }
+
+    /// Checks whether every bit of a raw integer mask is assigned to the
+    /// manager instance. The mask is not validated against the role enum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     #[bit_role(group = "content")]
+    ///     SendMessage = 1,
+    ///     #[bit_role(group = "content")]
+    ///     EditMessage = 2,
+    ///     BanMember = 4,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(3);
+    ///
+    /// assert!(roles.has_all_value(MyRole::CONTENT_MASK));
+    /// assert!(!roles.has_all_value(5));
+    /// ```
+    ///
+    /// * `mask` - The mask to check against the manager.
+    #[must_use]
+    pub fn has_all_value(&self, mask: usize) -> bool {
+        self.0.bitand(mask) == mask
+    }
+
+    /// Checks whether any bit of a raw integer mask is assigned to the manager
+    /// instance. This is an alias of the [has_value] method, named for
+    /// symmetry with [has_all_value].
+    ///
+    /// [has_value]: RoleManager::has_value
+    /// [has_all_value]: RoleManager::has_all_value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     #[bit_role(group = "content")]
+    ///     SendMessage = 1,
+    ///     #[bit_role(group = "content")]
+    ///     EditMessage = 2,
+    ///     BanMember = 4,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(MyRole::SendMessage.into());
+    ///
+    /// assert!(roles.has_any_value(MyRole::CONTENT_MASK));
+    /// assert!(!roles.has_any_value(MyRole::BanMember.into()));
+    /// ```
+    ///
+    /// * `mask` - The mask to check against the manager.
+    #[must_use]
+    pub fn has_any_value(&self, mask: usize) -> bool {
+        self.has_value(mask)
+    }
+
+    /// Converts the manager instance into a [RoleManagerUnchecked], preserving
+    /// its value. See [RoleManagerUnchecked::into_checked] for the reverse
+    /// conversion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRole,
+    ///     RoleValue,
+    /// };
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let mut roles = MyRole::from_value(MyRole::Staff.into()).into_unchecked();
+    ///
+    /// // Use a raw integer value for role.
+    /// roles
+    ///     .try_add_one(RoleValue::Raw(2))
+    ///     .expect("invalid role value");
+    ///
+    /// assert_eq!(roles.get_value(), 3);
+    /// ```
+    #[must_use]
+    pub fn into_unchecked(self) -> RoleManagerUnchecked<T> {
+        RoleManagerUnchecked(self.0, PhantomData)
+    }
+
+    /// Grants every role in a raw integer mask, such as a group mask, to the
+    /// manager instance. The mask is not validated against the role enum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     #[bit_role(group = "content")]
+    ///     SendMessage = 1,
+    ///     #[bit_role(group = "content")]
+    ///     EditMessage = 2,
+    ///     BanMember = 4,
+    /// }
+    ///
+    /// let mut roles = MyRole::empty();
+    ///
+    /// // Grant the roles in the `content` group.
+    /// roles.grant_mask(MyRole::CONTENT_MASK);
+    ///
+    /// assert_eq!(roles.get_value(), 3);
+    /// ```
+    ///
+    /// * `mask` - The mask of the roles to grant.
+    pub fn grant_mask(&mut self, mask: usize) -> &mut Self {
+        self.0.bitor_assign(mask);
+        self
+    }
+
+    /// Revokes every role in a raw integer mask, such as a group mask, from
+    /// the manager instance. The mask is not validated against the role enum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     #[bit_role(group = "content")]
+    ///     SendMessage = 1,
+    ///     #[bit_role(group = "content")]
+    ///     EditMessage = 2,
+    ///     BanMember = 4,
+    /// }
+    ///
+    /// let mut roles = MyRole::from_value(7);
+    ///
+    /// // Revoke the roles in the `content` group.
+    /// roles.revoke_mask(MyRole::CONTENT_MASK);
+    ///
+    /// assert_eq!(roles.get_value(), MyRole::BanMember.into());
+    /// ```
+    ///
+    /// * `mask` - The mask of the roles to revoke.
+    pub fn revoke_mask(&mut self, mask: usize) -> &mut Self {
+        self.0.bitand_assign(!mask);
+        self
+    }
+
+    /// Creates a new [RoleManager] instance holding the roles assigned to every
+    /// one of the provided managers. This is the counterpart of the [combine]
+    /// method. An empty slice of managers results in an empty manager, so that
+    /// no roles are granted by default.
+    ///
+    /// [combine]: RoleManager::combine
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRole,
+    ///     RoleManager,
+    /// };
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let first = MyRole::from_value(3);
+    /// let second = MyRole::from_value(MyRole::Member.into());
+    ///
+    /// // Get the roles shared by both managers.
+    /// let roles = RoleManager::intersection_all(&[first, second]);
+    ///
+    /// assert_eq!(roles.get_value(), MyRole::Member.into());
+    /// ```
+    ///
+    /// * `managers` - The managers to intersect.
+    #[must_use]
+    pub fn intersection_all(managers: &[Self]) -> Self {
+        RoleManager(
+            managers
+                .iter()
+                .map(|manager| manager.0)
+                .reduce(BitAnd::bitand)
+                .unwrap_or(0),
+            PhantomData,
+        )
+    }
+
+    /// Checks whether the value of the manager instance is exactly equal to a
+    /// raw integer value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(3);
+    ///
+    /// assert!(roles.is_value(3));
+    /// assert!(!roles.is_value(1));
+    /// ```
+    ///
+    /// * `value` - The value to compare against the manager.
+    #[must_use]
+    pub fn is_value(&self, value: usize) -> bool {
+        self.0 == value
+    }
+
+    /// Returns the number of bits set in the manager instance within the range
+    /// of bit positions `[start, end)`. Positions beyond the width of `usize`
+    /// are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    ///     Admin = 4,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(7);
+    ///
+    /// assert_eq!(roles.count_range(1, 3), 2);
+    /// ```
+    ///
+    /// * `start` - The first bit position of the range.
+    /// * `end` - The bit position past the end of the range.
+    #[must_use]
+    pub fn count_range(&self, start: u32, end: u32) -> u32 {
+        let below = |position: u32| 1usize.checked_shl(position).map_or(usize::MAX, |bit| bit - 1);
+        let mask = below(end) & !below(start);
+
+        self.0.bitand(mask).count_ones()
+    }
+
+    /// Returns a new [RoleManager] instance with each set bit of the manager
+    /// instance moved to the position computed by the provided closure. Bits
+    /// mapped to the same position are combined, and bits mapped beyond the
+    /// width of `usize` are dropped. The result is not validated against the
+    /// role enum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    ///     Admin = 4,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(MyRole::Staff.into());
+    ///
+    /// // Migrate the `Staff` bit to the position of `Admin`.
+    /// let migrated = roles.remap(|position| if position == 0 { 2 } else { position });
+    ///
+    /// assert!(migrated.has_one(MyRole::Admin));
+    /// ```
+    ///
+    /// * `f` - The closure computing the new position of each bit.
+    #[must_use]
+    pub fn remap<F>(&self, f: F) -> Self
+    where
+        F: Fn(u32) -> u32,
+    {
+        RoleManager(
+            self.bits()
+                .filter_map(|position| 1usize.checked_shl(f(position)))
+                .fold(0, |value, bit| value | bit),
+            PhantomData,
+        )
+    }
+
+    /// Returns a new [RoleManager] instance with the bits of a raw integer
+    /// value toggled. The value is not validated against the role enum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    ///     Guest = 4,
+    /// }
+    ///
+    /// let base = MyRole::from_value(3);
+    /// let roles = base.xor_value(6);
+    ///
+    /// assert_eq!(roles.get_value(), 5);
+    /// ```
+    ///
+    /// * `value` - The value to toggle.
+    #[must_use]
+    pub fn xor_value(&self, value: usize) -> Self {
+        RoleManager(self.0 ^ value, PhantomData)
+    }
+
+    /// Returns a new [RoleManager] instance with the bits of a raw integer
+    /// value cleared. The value is not validated against the role enum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    ///     Guest = 4,
+    /// }
+    ///
+    /// let base = MyRole::from_value(7);
+    /// let roles = base.and_not_value(6);
+    ///
+    /// assert_eq!(roles.get_value(), MyRole::Staff.into());
+    /// ```
+    ///
+    /// * `value` - The value to clear.
+    #[must_use]
+    pub fn and_not_value(&self, value: usize) -> Self {
+        RoleManager(self.0 & !value, PhantomData)
+    }
+
+    /// Returns the zero-based bit position of a role. The zero variant does not
+    /// occupy a bit, so `usize::BITS` is returned for it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRole,
+    ///     RoleManager,
+    /// };
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// assert_eq!(RoleManager::bit_for(MyRole::Member), 1);
+    /// assert_eq!(RoleManager::bit_for(MyRole::None), usize::BITS);
+    /// ```
+    ///
+    /// * `role` - The role to get the bit position of.
+    #[must_use]
+    pub fn bit_for(role: T) -> u32 {
+        role.value().trailing_zeros()
+    }
+
+    /// Creates a new [RoleManager] instance with the bits at the provided
+    /// zero-based positions set. This is the inverse of the [bit_for] method.
+    /// The positions are not validated against the role enum.
+    ///
+    /// [bit_for]: RoleManager::bit_for
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the positions is beyond the width of `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRole,
+    ///     RoleManager,
+    /// };
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let roles = RoleManager::<MyRole>::from_positions(&[0, 1]);
+    ///
+    /// assert!(roles.has_all(vec![MyRole::Staff, MyRole::Member]));
+    /// ```
+    ///
+    /// * `positions` - The positions of the bits to set.
+    #[must_use]
+    pub fn from_positions(positions: &[u32]) -> Self {
+        RoleManager(
+            positions.iter().fold(0, |value, position| {
+                value
+                    | 1usize
+                        .checked_shl(*position)
+                        .expect("`positions` contain a position beyond the width of `usize`")
+            }),
+            PhantomData,
+        )
+    }
+
+    /// Returns the Jaccard similarity of the manager instance and the other
+    /// manager, i.e. the number of bits set in both over the number of bits
+    /// set in either. Two empty managers are considered identical, with a
+    /// similarity of `1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    ///     Guest = 4,
+    /// }
+    ///
+    /// let first = MyRole::from_value(3);
+    /// let second = MyRole::from_value(6);
+    ///
+    /// assert_eq!(first.jaccard(&second), 1.0 / 3.0);
+    /// ```
+    ///
+    /// * `other` - The manager to compare against.
+    #[must_use]
+    pub fn jaccard(&self, other: &Self) -> f64 {
+        let union = (self.0 | other.0).count_ones();
+
+        if union == 0 {
+            return 1.0;
+        }
+
+        f64::from(self.0.bitand(other.0).count_ones()) / f64::from(union)
+    }
+
+    /// Returns an error listing the missing and extra role values unless the
+    /// manager instance holds exactly the provided roles and nothing else.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRole,
+    ///     RoleError,
+    /// };
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    ///     Guest = 4,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(3);
+    ///
+    /// assert!(roles.require_exactly(&[MyRole::Staff, MyRole::Member]).is_ok());
+    /// assert!(matches!(
+    ///     roles.require_exactly(&[MyRole::Staff, MyRole::Guest]),
+    ///     Err(RoleError::Mismatch { missing, extra }) if missing == vec![4] && extra == vec![2]
+    /// ));
+    /// ```
+    ///
+    /// * `roles` - The exact roles that must be assigned.
+    pub fn require_exactly(&self, roles: &[T]) -> Result<(), RoleError> {
+        let expected = roles.iter().fold(0, |mask, role| mask | role.value());
+
+        if self.0 == expected {
+            return Ok(());
+        }
+
+        let split = |mask: usize| {
+            RoleManager::<T>(mask, PhantomData)
+                .bits()
+                .map(|position| 1 << position)
+                .collect::<Vec<_>>()
+        };
+
+        Err(RoleError::Mismatch {
+            missing: split(expected & !self.0),
+            extra: split(self.0 & !expected),
+        })
+    }
+
+    /// Layers the manager instance over a base manager, returning a new
+    /// [RoleManager] instance that holds the roles of both. The roles of the
+    /// manager instance are added on top of the base roles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    ///     Guest = 4,
+    /// }
+    ///
+    /// let defaults = MyRole::from_value(MyRole::Guest.into());
+    /// let overrides = MyRole::from_value(MyRole::Staff.into());
+    ///
+    /// let roles = overrides.over(&defaults);
+    ///
+    /// assert!(roles.has_all(vec![MyRole::Staff, MyRole::Guest]));
+    /// ```
+    ///
+    /// * `base` - The base manager to layer over.
+    #[must_use]
+    pub fn over(&self, base: &Self) -> Self {
+        RoleManager(base.0 | self.0, PhantomData)
+    }
+
+    /// Layers the manager instance under a base manager, returning a new
+    /// [RoleManager] instance in which the base wins. The roles of the manager
+    /// instance that are not held by the base are cleared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    ///     Guest = 4,
+    /// }
+    ///
+    /// let ceiling = MyRole::from_value(3);
+    /// let requested = MyRole::from_value(6);
+    ///
+    /// let roles = requested.under(&ceiling);
+    ///
+    /// assert_eq!(roles.get_value(), MyRole::Member.into());
+    /// ```
+    ///
+    /// * `base` - The base manager to layer under.
+    #[must_use]
+    pub fn under(&self, base: &Self) -> Self {
+        RoleManager(base.0 & self.0, PhantomData)
+    }
+
+    /// Returns the number of bits set in the manager instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(3);
+    ///
+    /// assert_eq!(roles.count_ones(), 2);
+    /// ```
+    #[must_use]
+    pub fn count_ones(&self) -> u32 {
+        self.0.count_ones()
+    }
+
+    /// Returns the number of leading zero bits in the value of the manager
+    /// instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(MyRole::Member.into());
+    ///
+    /// assert_eq!(roles.leading_zeros(), usize::BITS - 2);
+    /// ```
+    #[must_use]
+    pub fn leading_zeros(&self) -> u32 {
+        self.0.leading_zeros()
+    }
+
+    /// Returns the value of the manager instance widened to a `u64`, usable as
+    /// a cache key that is stable across platforms. On targets where `usize`
+    /// is wider than 64 bits, the value is truncated to its low 64 bits. Use
+    /// the [try_as_u64] method to detect this instead.
+    ///
+    /// [try_as_u64]: RoleManager::try_as_u64
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(3);
+    ///
+    /// assert_eq!(roles.stable_key(), 3);
+    /// ```
+    #[must_use]
+    pub fn stable_key(&self) -> u64 {
+        self.0 as u64
+    }
+
+    /// Returns the number of roles assigned to the manager instance. This is
+    /// an alias of [RoleManager::count_ones], matching
+    /// [RoleManagerUnchecked::count].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(3);
+    ///
+    /// assert_eq!(roles.count(), 2);
+    /// ```
+    #[must_use]
+    pub fn count(&self) -> u32 {
+        self.count_ones()
+    }
+
+    /// Checks whether no roles are assigned to the manager instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let roles = MyRole::empty();
+    ///
+    /// assert!(roles.is_empty());
+    /// ```
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Removes every role from the manager instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let mut roles = MyRole::from_value(3);
+    ///
+    /// roles.clear();
+    ///
+    /// assert!(roles.is_empty());
+    /// ```
+    pub fn clear(&mut self) -> &mut Self {
+        self.0 = 0;
+        self
+    }
+}
+
+impl<T> PartialEq<Self> for RoleManager<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T> Eq for RoleManager<T> {}
+
+/// Compares the manager instance to a single role. The manager is equal to the
+/// role only when it holds exactly that role and nothing else, unlike
+/// [RoleManager::has_one], which allows other roles to be assigned as well.
+///
+/// # Examples
+///
+/// ```
+/// use bit_roles::BitRole;
+///
+/// #[derive(Debug, BitRole, Copy, Clone)]
+/// enum MyRole {
+///     None = 0,
+///     Staff = 1,
+///     Member = 2,
+/// }
+///
+/// let mut roles = MyRole::empty();
+/// roles.add_one(MyRole::Staff);
+///
+/// assert!(roles == MyRole::Staff);
+///
+/// roles.add_one(MyRole::Member);
+///
+/// assert!(roles != MyRole::Staff);
+/// assert!(roles.has_one(MyRole::Staff));
+/// ```
+impl<T> PartialEq<T> for RoleManager<T>
+where
+    T: RoleVariant,
+{
+    fn eq(&self, role: &T) -> bool {
+        self.0 == role.value()
+    }
+}
+
+impl<T> AsRef<usize> for RoleManager<T> {
+    /// Returns a reference to the value of the manager instance.
+    fn as_ref(&self) -> &usize {
+        &self.0
+    }
+}
+
+impl<T> Borrow<usize> for RoleManager<T> {
+    /// Borrows the value of the manager instance.
+    fn borrow(&self) -> &usize {
+        &self.0
+    }
+}
+
+impl<T> TryFrom<&[&str]> for RoleManager<T>
+where
+    T: RoleVariant + BitRoleImpl<T>,
+{
+    type Error = RoleError;
+
+    /// Creates a new [RoleManager] instance from the names of role variants.
+    /// See [BitRoleImpl::from_names].
+    fn try_from(names: &[&str]) -> Result<Self, Self::Error> {
+        T::from_names(names)
+    }
+}
+
+impl<T> Not for RoleManager<T>
+where
+    T: BitRoleImpl<T>,
+{
+    type Output = Self;
+
+    /// Returns a new [RoleManager] instance with every declared role except
+    /// the ones assigned to this manager.
+    fn not(self) -> Self::Output {
+        RoleManager(T::DECLARED_MASK & !self.0, PhantomData)
+    }
+}
+
+impl<T> RoleManager<T>
+where
+    T: RoleVariant + BitRoleImpl<T>,
+{
+    /// Creates a new [RoleManager] instance with the provided roles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRole,
+    ///     RoleManager,
+    /// };
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// // Create a manager instance with the provided roles.
+    /// let roles = RoleManager::from_roles(&[MyRole::Staff, MyRole::Member]);
+    ///
+    /// assert!(roles.has_all(vec![MyRole::Staff, MyRole::Member]));
+    /// ```
+    ///
+    /// * `roles` - The roles for the manager.
+    pub fn from_roles(roles: &[T]) -> Self {
+        let mut manager = RoleManager(0, PhantomData);
+        manager.add_iter(roles.iter().copied());
+        manager
+    }
+
+    /// Adds a single role to the manager instance. In debug builds, this
+    /// asserts that the manager only contains declared roles afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    /// }
+    ///
+    /// let mut roles = MyRole::empty();
+    ///
+    /// // Add a new role to the manager.
+    /// roles.add_one(MyRole::Staff);
+    ///
+    /// assert!(roles.has_one(MyRole::Staff));
+    /// ```
+    ///
+    /// * `role` - The role to add to the manager.
+    pub fn add_one(&mut self, role: T) -> &mut Self {
+        debug_assert!(role.is_valid(), "`role` holds an invalid value");
+        self.0.bitor_assign(role.value());
+        self.debug_assert_valid();
+        self
+    }
+
+    /// Removes a single role from the manager instance. In debug builds, this
+    /// asserts that the manager only contains declared roles afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    /// }
+    ///
+    /// let mut roles = MyRole::empty();
+    ///
+    /// // Remove a role from the manager.
+    /// roles.remove_one(MyRole::Staff);
+    ///
+    /// assert!(roles.not_one(MyRole::Staff));
+    /// ```
+    ///
+    /// * `role` - The role to remove from the manager.
+    pub fn remove_one(&mut self, role: T) -> &mut Self {
+        debug_assert!(role.is_valid(), "`role` holds an invalid value");
+        self.0.bitand_assign(!role.value());
+        self.debug_assert_valid();
+        self
+    }
+
+    /// Adds multiple roles to the manager instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let mut roles = MyRole::empty();
+    ///
+    /// // Add multiple new roles to the manager.
+    /// roles.add_all(vec![MyRole::Staff, MyRole::Member]);
+    ///
+    /// assert!(roles.has_all(vec![MyRole::Staff, MyRole::Member]));
+    /// ```
+    ///
+    /// * `roles` - The roles to add to the manager.
+    pub fn add_all(&mut self, roles: Vec<T>) -> &mut Self {
+        self.add_iter(roles)
+    }
+
+    /// Adds roles from an iterator to the manager instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let source = [MyRole::None, MyRole::Staff, MyRole::Member];
+    /// let mut roles = MyRole::empty();
+    ///
+    /// // Add the roles from an iterator to the manager.
+    /// roles.add_iter(source.iter().copied().skip(1));
+    ///
+    /// assert!(roles.has_all(vec![MyRole::Staff, MyRole::Member]));
+    /// ```
+    ///
+    /// * `roles` - The roles to add to the manager.
+    pub fn add_iter(&mut self, roles: impl IntoIterator<Item = T>) -> &mut Self {
+        roles.into_iter().for_each(|role| {
+            self.add_one(role);
+        });
+
+        self
+    }
+
+    /// Removes multiple roles from the manager instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let mut roles = MyRole::empty();
+    ///
+    /// // Remove multiple roles from the manager.
+    /// roles.remove_all(vec![MyRole::Staff, MyRole::Member]);
+    ///
+    /// assert!(roles.not_all(vec![MyRole::Staff, MyRole::Member]));
+    /// ```
+    ///
+    /// * `roles` - The roles to remove from the manager.
+    pub fn remove_all(&mut self, roles: Vec<T>) -> &mut Self {
+        self.remove_iter(roles)
+    }
+
+    /// Removes roles from an iterator from the manager instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let source = [MyRole::None, MyRole::Staff, MyRole::Member];
+    /// let mut roles = MyRole::empty();
+    /// roles.add_all(vec![MyRole::Staff, MyRole::Member]);
+    ///
+    /// // Remove the roles from an iterator from the manager.
+    /// roles.remove_iter(source.iter().copied().skip(1));
+    ///
+    /// assert!(roles.not_any(vec![MyRole::Staff, MyRole::Member]));
+    /// ```
+    ///
+    /// * `roles` - The roles to remove from the manager.
+    pub fn remove_iter(&mut self, roles: impl IntoIterator<Item = T>) -> &mut Self {
+        roles.into_iter().for_each(|role| {
+            self.remove_one(role);
+        });
+
+        self
+    }
+
+    /// Checks whether the value of the manager instance only contains declared
+    /// roles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(MyRole::Staff.into());
+    ///
+    /// assert!(roles.is_valid());
+    /// ```
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.0 & !T::DECLARED_MASK == 0
+    }
+
+    /// Toggles every declared role of the manager instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    ///     Guest = 4,
+    /// }
+    ///
+    /// let mut roles = MyRole::from_value(MyRole::Staff.into());
+    ///
+    /// // Invert the roles of the manager.
+    /// roles.flip();
+    ///
+    /// assert!(roles.not_one(MyRole::Staff));
+    /// assert!(roles.has_all(vec![MyRole::Member, MyRole::Guest]));
+    /// ```
+    pub fn flip(&mut self) -> &mut Self {
+        self.0.bitxor_assign(T::DECLARED_MASK);
+        self
+    }
+
+    /// Returns every declared role along with whether it is assigned to the
+    /// manager instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(MyRole::Member.into());
+    /// let report = roles.report();
+    ///
+    /// assert!(matches!(
+    ///     report.as_slice(),
+    ///     [(MyRole::Staff, false), (MyRole::Member, true)]
+    /// ));
+    /// ```
+    #[must_use]
+    pub fn report(&self) -> Vec<(T, bool)> {
+        T::all_roles()
+            .into_iter()
+            .map(|role| (role, self.has_one(role)))
+            .collect()
+    }
+
+    /// Returns the roles added and removed when going from the manager
+    /// instance to the other manager.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let previous = MyRole::from_value(MyRole::Staff.into());
+    /// let current = MyRole::from_value(MyRole::Member.into());
+    ///
+    /// let diff = previous.diff_roles(&current);
+    ///
+    /// assert!(matches!(diff.added.as_slice(), [MyRole::Member]));
+    /// assert!(matches!(diff.removed.as_slice(), [MyRole::Staff]));
+    /// ```
+    ///
+    /// * `other` - The manager to compare against.
+    #[must_use]
+    pub fn diff_roles(&self, other: &Self) -> RoleDiff<T> {
+        let mut diff = RoleDiff {
+            added: Vec::new(),
+            removed: Vec::new(),
+        };
+
+        for role in T::all_roles() {
+            match (self.has_one(role), other.has_one(role)) {
+                (false, true) => diff.added.push(role),
+                (true, false) => diff.removed.push(role),
+                _ => {}
+            }
+        }
+
+        diff
+    }
+
+    /// Returns the roles assigned to the manager instance that satisfy the
+    /// provided predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     ReadMessage = 1,
+    ///     WriteMessage = 2,
+    ///     WriteProfile = 4,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(7);
+    ///
+    /// // Get the write permissions assigned to the manager.
+    /// let write_roles = roles.roles_where(|role| role.name().starts_with("Write"));
+    ///
+    /// assert!(matches!(
+    ///     write_roles.as_slice(),
+    ///     [MyRole::WriteMessage, MyRole::WriteProfile]
+    /// ));
+    /// ```
+    ///
+    /// * `pred` - The predicate to filter the roles with.
+    #[must_use]
+    pub fn roles_where<F>(&self, pred: F) -> Vec<T>
+    where
+        F: Fn(T) -> bool,
+    {
+        T::all_roles()
+            .into_iter()
+            .filter(|role| self.has_one(*role) && pred(*role))
+            .collect()
+    }
+
+    /// Returns the role assigned to the manager instance with the largest
+    /// value, or `None` if the manager is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    ///     Admin = 4,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(5);
+    ///
+    /// assert!(matches!(roles.highest(), Some(MyRole::Admin)));
+    /// ```
+    #[must_use]
+    pub fn highest(&self) -> Option<T> {
+        T::all_roles()
+            .into_iter()
+            .filter(|role| self.has_one(*role))
+            .max_by_key(|role| role.value())
+    }
+
+    /// Returns the role assigned to the manager instance with the smallest
+    /// non-zero value, or `None` if the manager is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    ///     Admin = 4,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(5);
+    ///
+    /// assert!(matches!(roles.lowest(), Some(MyRole::Staff)));
+    /// ```
+    #[must_use]
+    pub fn lowest(&self) -> Option<T> {
+        T::all_roles()
+            .into_iter()
+            .filter(|role| self.has_one(*role))
+            .min_by_key(|role| role.value())
+    }
+
+    /// Clears the bits of the manager instance that do not belong to any
+    /// declared role, returning the number of bits removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRole,
+    ///     RoleManager,
+    /// };
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    /// }
+    ///
+    /// // A legacy value with two stray bits, built without validation.
+    /// let mut roles = RoleManager::<MyRole>::from_value_unchecked(0b1101);
+    ///
+    /// assert_eq!(roles.clear_invalid(), 2);
+    /// assert_eq!(roles.get_value(), MyRole::Staff.into());
+    /// ```
+    pub fn clear_invalid(&mut self) -> u32 {
+        let stray = self.0 & !T::DECLARED_MASK;
+        self.0.bitand_assign(T::DECLARED_MASK);
+        stray.count_ones()
+    }
+
+    /// Returns an iterator over the roles assigned to the manager instance,
+    /// paired with their names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(MyRole::Member.into());
+    /// let names = roles.iter_named().map(|(_, name)| name).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(names, vec!["Member"]);
+    /// ```
+    pub fn iter_named(&self) -> impl Iterator<Item = (T, &'static str)> {
+        let value = self.0;
+
+        T::all_roles()
+            .into_iter()
+            .filter(move |role| value.bitand(role.value()) != 0)
+            .map(|role| (role, role.name()))
+    }
+
+    /// Folds over the roles assigned to the manager instance in declaration
+    /// order, stopping at the first error returned by the closure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(3);
+    ///
+    /// // Count the assigned roles, stopping past a threshold.
+    /// let count = roles.try_fold_roles(0, |count, _| {
+    ///     if count < 5 {
+    ///         Ok(count + 1)
+    ///     } else {
+    ///         Err("too many roles")
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(count, Ok(2));
+    /// ```
+    ///
+    /// * `init` - The initial value of the accumulator.
+    /// * `f` - The closure folding each role into the accumulator.
+    pub fn try_fold_roles<B, E, F>(&self, init: B, f: F) -> Result<B, E>
+    where
+        F: FnMut(B, T) -> Result<B, E>,
+    {
+        T::all_roles()
+            .into_iter()
+            .filter(|role| self.has_one(*role))
+            .try_fold(init, f)
+    }
+
+    /// Returns the roles assigned to the manager instance as an ordered set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(MyRole::Member.into());
+    /// let set = roles.as_set();
+    ///
+    /// assert!(set.contains(&MyRole::Member));
+    /// assert!(!set.contains(&MyRole::Staff));
+    /// ```
+    #[must_use]
+    pub fn as_set(&self) -> BTreeSet<T>
+    where
+        T: Ord,
+    {
+        T::all_roles()
+            .into_iter()
+            .filter(|role| self.has_one(*role))
+            .collect()
+    }
+
+    /// Toggles every declared role of the manager instance except the provided
+    /// roles, which keep their current state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    ///     Guest = 4,
+    /// }
+    ///
+    /// let mut roles = MyRole::from_value(MyRole::Staff.into());
+    ///
+    /// // Invert the roles of the manager, pinning the `Staff` role.
+    /// roles.invert_except(&[MyRole::Staff]);
+    ///
+    /// assert!(roles.has_all(vec![MyRole::Staff, MyRole::Member, MyRole::Guest]));
+    /// ```
+    ///
+    /// * `keep` - The roles to leave untouched.
+    pub fn invert_except(&mut self, keep: &[T]) -> &mut Self {
+        let pinned = keep.iter().fold(0, |mask, role| mask | role.value());
+        self.0.bitxor_assign(T::DECLARED_MASK & !pinned);
+        self
+    }
+
+    /// Returns the positions of the bits set in the manager instance that do
+    /// not belong to any declared role, from the least significant bit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRole,
+    ///     RoleManager,
+    /// };
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    /// }
+    ///
+    /// // A legacy value with two stray bits, built without validation.
+    /// let roles = RoleManager::<MyRole>::from_value_unchecked(0b1101);
+    ///
+    /// assert_eq!(roles.diagnostics(), vec![2, 3]);
+    /// ```
+    #[must_use]
+    pub fn diagnostics(&self) -> Vec<u32> {
+        self.bits()
+            .filter(|position| T::DECLARED_MASK & (1 << position) == 0)
+            .collect()
+    }
+
+    /// Removes a single role from the manager instance, returning an error if
+    /// the role is not assigned to it. This is a strict equivalent of the
+    /// [remove_one] method.
+    ///
+    /// [remove_one]: RoleManager::remove_one
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRole,
+    ///     RoleError,
+    /// };
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let mut roles = MyRole::from_value(MyRole::Staff.into());
+    ///
+    /// assert!(matches!(
+    ///     roles.try_revoke(MyRole::Member),
+    ///     Err(RoleError::NotHeld(2))
+    /// ));
+    ///
+    /// roles.try_revoke(MyRole::Staff).expect("role not held");
+    ///
+    /// assert!(roles.not_one(MyRole::Staff));
+    /// ```
+    ///
+    /// * `role` - The role to remove.
+    pub fn try_revoke(&mut self, role: T) -> Result<&mut Self, RoleError> {
+        if self.not_one(role) {
+            return Err(RoleError::NotHeld(role.value()));
+        }
+
+        Ok(self.remove_one(role))
+    }
+
+    /// Returns whether each bit spanned by the declared roles is set in the
+    /// manager instance, indexed by bit position. The length of the result is
+    /// [BitRoleImpl::DECLARED_BITS].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    ///     Guest = 4,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(5);
+    ///
+    /// assert_eq!(roles.matrix(), vec![true, false, true]);
+    /// ```
+    #[must_use]
+    pub fn matrix(&self) -> Vec<bool> {
+        (0..T::DECLARED_BITS)
+            .map(|position| self.0.bitand(1 << position) != 0)
+            .collect()
+    }
+
+    /// Returns an iterator over the roles assigned to the manager instance in
+    /// ascending order of their values. The iterator is double-ended, so the
+    /// most significant roles can be visited first using `.rev()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    ///     Admin = 4,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(5);
+    ///
+    /// assert!(matches!(
+    ///     roles.iter().rev().collect::<Vec<_>>().as_slice(),
+    ///     [MyRole::Admin, MyRole::Staff]
+    /// ));
+    /// ```
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = T> + ExactSizeIterator {
+        let mut roles = T::all_roles()
+            .into_iter()
+            .filter(|role| self.has_one(*role))
+            .collect::<Vec<_>>();
+
+        roles.sort_by_key(|role| role.value());
+        roles.into_iter()
+    }
+
+    /// Returns the names of the roles assigned to the manager instance, joined
+    /// using the provided separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(3);
+    ///
+    /// assert_eq!(roles.to_names(","), "Staff,Member");
+    /// ```
+    ///
+    /// * `sep` - The separator placed between the names.
+    #[must_use]
+    pub fn to_names(&self, sep: &str) -> String {
+        self.iter_named()
+            .map(|(_, name)| name)
+            .collect::<Vec<_>>()
+            .join(sep)
+    }
+
+    /// Creates a new [RoleManager] instance from a string of role names split
+    /// by the provided separator, returning it along with the names that do
+    /// not match any role variant. Whitespace around the names is trimmed,
+    /// and empty names are ignored. This is a lenient equivalent of the
+    /// [BitRoleImpl::from_names] method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRole,
+    ///     RoleManager,
+    /// };
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let (roles, unknown) = RoleManager::<MyRole>::parse_names_lossy("Staff, Guest", ",");
+    ///
+    /// assert!(roles.has_one(MyRole::Staff));
+    /// assert_eq!(unknown, vec!["Guest".to_string()]);
+    /// ```
+    ///
+    /// * `s` - The string of role names.
+    /// * `sep` - The separator placed between the names.
+    #[must_use]
+    pub fn parse_names_lossy(s: &str, sep: &str) -> (Self, Vec<String>) {
+        let mut manager = T::empty();
+        let mut unknown = Vec::new();
+
+        for name in s.split(sep).map(str::trim).filter(|name| !name.is_empty()) {
+            match T::from_name(name) {
+                Some(role) => {
+                    manager.add_one(role);
+                }
+                None => unknown.push(name.to_string()),
+            }
+        }
+
+        (manager, unknown)
+    }
+
+    /// Validates and adds multiple raw integer role values to the manager
+    /// instance. Each value must be zero or a single declared role. The values
+    /// are applied atomically, so the manager is left unchanged if any of
+    /// them is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRole,
+    ///     RoleError,
+    /// };
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let mut roles = MyRole::empty();
+    ///
+    /// roles.try_grant_values(&[1, 2]).expect("invalid role value");
+    ///
+    /// assert_eq!(roles.get_value(), 3);
+    /// assert!(matches!(
+    ///     roles.try_grant_values(&[4]),
+    ///     Err(RoleError::Undeclared(4))
+    /// ));
+    /// ```
+    ///
+    /// * `values` - The role values to add to the manager.
+    pub fn try_grant_values(&mut self, values: &[usize]) -> Result<&mut Self, RoleError> {
+        let value = values.iter().try_fold(0, |mask, value| {
+            if !is_valid_role(*value) {
+                Err(RoleError::InvalidRole(*value))
+            } else if value & !T::DECLARED_MASK != 0 {
+                Err(RoleError::Undeclared(*value))
+            } else {
+                Ok(mask | value)
+            }
+        })?;
+
+        self.0.bitor_assign(value);
+        Ok(self)
+    }
+
+    /// Creates a new [RoleManager] instance from a raw integer value, returning
+    /// `None` if the value contains undeclared roles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRole,
+    ///     RoleManager,
+    /// };
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// assert!(RoleManager::<MyRole>::checked_from_value(3).is_some());
+    /// assert!(RoleManager::<MyRole>::checked_from_value(4).is_none());
+    /// ```
+    ///
+    /// * `value` - The value of the manager.
+    #[must_use]
+    pub fn checked_from_value(value: usize) -> Option<Self> {
+        (value & !T::DECLARED_MASK == 0).then_some(RoleManager(value, PhantomData))
+    }
+
+    /// Returns the declared roles that are not assigned to the manager
+    /// instance, in declaration order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(MyRole::Staff.into());
+    ///
+    /// assert!(matches!(roles.available().as_slice(), [MyRole::Member]));
+    /// ```
+    #[must_use]
+    pub fn available(&self) -> Vec<T> {
+        T::all_roles()
+            .into_iter()
+            .filter(|role| self.not_one(*role))
+            .collect()
+    }
+
+    /// Splits the declared roles into the ones assigned to the manager
+    /// instance and the ones that are not, in declaration order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let roles = MyRole::from_value(MyRole::Staff.into());
+    /// let (assigned, available) = roles.partition();
+    ///
+    /// assert!(matches!(assigned.as_slice(), [MyRole::Staff]));
+    /// assert!(matches!(available.as_slice(), [MyRole::Member]));
+    /// ```
+    #[must_use]
+    pub fn partition(&self) -> (Vec<T>, Vec<T>) {
+        T::all_roles()
+            .into_iter()
+            .partition(|role| self.has_one(*role))
+    }
+
+    /// Adds the role to the manager instance if `enabled` is `true`, and
+    /// removes it otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    /// }
+    ///
+    /// let mut roles = MyRole::empty();
+    ///
+    /// // Apply the state of a checkbox.
+    /// roles.set_one(MyRole::Staff, true);
+    ///
+    /// assert!(roles.has_one(MyRole::Staff));
+    /// ```
+    ///
+    /// * `role` - The role to add or remove.
+    /// * `enabled` - Whether the role should be assigned.
+    pub fn set_one(&mut self, role: T, enabled: bool) -> &mut Self {
+        if enabled {
+            self.add_one(role)
+        } else {
+            self.remove_one(role)
+        }
+    }
+
+    /// Applies the desired state of multiple roles to the manager instance.
+    /// Each role is added if its flag is `true`, and removed otherwise. The
+    /// entries are applied in order, so a later entry for the same role wins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRole;
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let mut roles = MyRole::from_value(MyRole::Staff.into());
+    ///
+    /// // Apply the states of a form submission.
+    /// roles.set_all(&[(MyRole::Staff, false), (MyRole::Member, true)]);
+    ///
+    /// assert_eq!(roles.get_value(), MyRole::Member.into());
+    /// ```
+    ///
+    /// * `entries` - The roles paired with whether they should be assigned.
+    pub fn set_all(&mut self, entries: &[(T, bool)]) -> &mut Self {
+        for (role, enabled) in entries {
+            self.set_one(*role, *enabled);
+        }
+
+        self
+    }
+}
+
+impl<T> RoleManager<T>
+where
+    T: BitRoleImpl<T>,
+{
+    /// Asserts that the value of the manager instance only contains declared
+    /// roles. This is a no-op in release builds. Every role mutator performs
+    /// this check; the raw value mutators do not.
+    fn debug_assert_valid(&self) {
+        debug_assert!(
+            self.0 & !T::DECLARED_MASK == 0,
+            "manager contains undeclared roles"
+        );
+    }
+}
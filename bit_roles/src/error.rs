@@ -1,10 +1,56 @@
 use thiserror::Error;
 
-/// The error raised when working with role values.
+/// The error raised when working with role values. New variants may be added
+/// in future releases, so matches on this enum must include a wildcard arm.
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum RoleError {
     /// Raised when the provided role holds a value that is neither zero nor a
     /// power of two.
     #[error("invalid role value: `{0}` is neither zero nor a power of two")]
     InvalidRole(usize),
+    /// Raised when the provided role names do not match any role variant.
+    #[error("unknown role names: `{}`", .0.join("`, `"))]
+    UnknownNames(Vec<String>),
+    /// Raised when a role value does not fit in the target integer width.
+    #[error("role value overflow: `{0}` does not fit in the target integer width")]
+    Overflow(usize),
+    /// Raised when the manager does not hold the required roles.
+    #[error("missing roles: {}", format_values(.0))]
+    Missing(Vec<usize>),
+    /// Raised when a value contains bits that do not belong to any declared
+    /// role. Holds the undeclared bits.
+    #[error("undeclared roles: `{0}` does not belong to any declared role")]
+    Undeclared(usize),
+    /// Raised when revoking a role that is not assigned to the manager.
+    #[error("role not held: `{0}` is not assigned to the manager")]
+    NotHeld(usize),
+    /// Raised when the manager does not hold exactly the required roles.
+    #[error(
+        "role mismatch: missing roles: {}; extra roles: {}",
+        format_values(.missing),
+        format_values(.extra)
+    )]
+    Mismatch {
+        /// The values of the required roles that are not held.
+        missing: Vec<usize>,
+        /// The values of the held roles that are not required.
+        extra: Vec<usize>,
+    },
+}
+
+/// Formats role values as a comma-separated list of quoted values, or `none`
+/// if there are no values.
+///
+/// * `values` - The role values to format.
+fn format_values(values: &[usize]) -> String {
+    if values.is_empty() {
+        return "none".to_string();
+    }
+
+    values
+        .iter()
+        .map(|value| format!("`{value}`"))
+        .collect::<Vec<_>>()
+        .join(", ")
 }
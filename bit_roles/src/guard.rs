@@ -0,0 +1,107 @@
+use crate::{
+    RoleManager,
+    RoleVariant,
+};
+
+/// Returns a predicate checking whether each of the roles is assigned to a
+/// manager. Use it with [RoleManager::satisfies].
+///
+/// # Examples
+///
+/// ```
+/// use bit_roles::{
+///     all_of,
+///     none_of,
+///     BitRole,
+/// };
+///
+/// #[derive(Debug, BitRole, Copy, Clone)]
+/// enum MyRole {
+///     None = 0,
+///     Staff = 1,
+///     Member = 2,
+///     Banned = 4,
+/// }
+///
+/// let is_staff = all_of(&[MyRole::Staff, MyRole::Member]);
+/// let is_not_banned = none_of(&[MyRole::Banned]);
+///
+/// let mut roles = MyRole::empty();
+/// roles.add_all(vec![MyRole::Staff, MyRole::Member]);
+///
+/// // Compose the predicates into a single guard.
+/// assert!(roles.satisfies(|roles| is_staff(roles) && is_not_banned(roles)));
+/// ```
+///
+/// * `roles` - The roles that must all be assigned.
+pub fn all_of<T>(roles: &[T]) -> impl Fn(&RoleManager<T>) -> bool
+where
+    T: RoleVariant,
+{
+    let roles = roles.to_vec();
+    move |manager| manager.has_all(roles.clone())
+}
+
+/// Returns a predicate checking whether any one of the roles is assigned to a
+/// manager. Use it with [RoleManager::satisfies].
+///
+/// # Examples
+///
+/// ```
+/// use bit_roles::{
+///     any_of,
+///     BitRole,
+/// };
+///
+/// #[derive(Debug, BitRole, Copy, Clone)]
+/// enum MyRole {
+///     None = 0,
+///     Staff = 1,
+///     Member = 2,
+/// }
+///
+/// let roles = MyRole::from_value(MyRole::Member.into());
+///
+/// assert!(roles.satisfies(any_of(&[MyRole::Staff, MyRole::Member])));
+/// ```
+///
+/// * `roles` - The roles of which at least one must be assigned.
+pub fn any_of<T>(roles: &[T]) -> impl Fn(&RoleManager<T>) -> bool
+where
+    T: RoleVariant,
+{
+    let roles = roles.to_vec();
+    move |manager| manager.has_any(roles.clone())
+}
+
+/// Returns a predicate checking whether none of the roles is assigned to a
+/// manager. Use it with [RoleManager::satisfies].
+///
+/// # Examples
+///
+/// ```
+/// use bit_roles::{
+///     none_of,
+///     BitRole,
+/// };
+///
+/// #[derive(Debug, BitRole, Copy, Clone)]
+/// enum MyRole {
+///     None = 0,
+///     Staff = 1,
+///     Banned = 2,
+/// }
+///
+/// let roles = MyRole::from_value(MyRole::Staff.into());
+///
+/// assert!(roles.satisfies(none_of(&[MyRole::Banned])));
+/// ```
+///
+/// * `roles` - The roles that must not be assigned.
+pub fn none_of<T>(roles: &[T]) -> impl Fn(&RoleManager<T>) -> bool
+where
+    T: RoleVariant,
+{
+    let roles = roles.to_vec();
+    move |manager| manager.not_any(roles.clone())
+}
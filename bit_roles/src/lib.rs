@@ -17,6 +17,13 @@
 //! implement the `Into<usize>` trait for your role enum, along with deriving
 //! the [Copy] and [Clone] traits for it.
 //!
+//! Enable the `strict` feature to make `from_value` panic on values containing
+//! undeclared roles. Deserializing such values returns an error instead.
+//!
+//! Enable the `serde` feature to (de)serialize role managers using the
+//! `AsInteger` and `AsNames` wrappers. Role managers can also be deserialized
+//! directly from either representation.
+//!
 //! # Examples
 //!
 //! ```
@@ -51,7 +58,11 @@
 
 mod checked;
 mod error;
+mod guard;
+mod role_diff;
 mod role_value;
+#[cfg(feature = "serde")]
+mod serialization;
 mod unchecked;
 mod utils;
 
@@ -61,9 +72,65 @@ pub use bit_roles_macros::{
 };
 pub use checked::*;
 pub use error::RoleError;
+pub use guard::*;
+pub use role_diff::RoleDiff;
 pub use role_value::RoleValue;
+#[cfg(feature = "serde")]
+pub use serialization::*;
 pub use unchecked::*;
 pub use utils::is_valid_role;
 
-/// The role variant trait. All role enums must implement this trait.
-pub trait RoleVariant: Into<usize> + Copy {}
+/// The role variant trait. All role enums must implement this trait. It is
+/// implemented by the derive macros, but manual implementations must ensure
+/// that every variant holds a value that is either zero or a power of two. See
+/// [RoleVariant::is_valid].
+pub trait RoleVariant: Into<usize> + Copy {
+    /// Returns the integer value of the role variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRole,
+    ///     RoleVariant,
+    /// };
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// assert_eq!(MyRole::Member.value(), 2);
+    /// ```
+    fn value(self) -> usize {
+        self.into()
+    }
+
+    /// Checks whether the role variant holds a value that is either zero or a
+    /// power of two. In debug builds, the checked manager asserts this when
+    /// adding or removing roles through [RoleManager::add_one],
+    /// [RoleManager::remove_one] and the methods built on them. Other methods
+    /// trust the value of the role.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRole,
+    ///     RoleVariant,
+    /// };
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    /// }
+    ///
+    /// assert!(MyRole::Staff.is_valid());
+    /// ```
+    fn is_valid(self) -> bool {
+        is_valid_role(self.value())
+    }
+}
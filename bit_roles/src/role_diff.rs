@@ -0,0 +1,8 @@
+/// The roles that changed between two role managers.
+#[derive(Debug, Clone)]
+pub struct RoleDiff<T> {
+    /// Roles assigned to the other manager but not to this one.
+    pub added: Vec<T>,
+    /// Roles assigned to this manager but not to the other one.
+    pub removed: Vec<T>,
+}
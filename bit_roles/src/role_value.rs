@@ -75,6 +75,37 @@ where
             .ok_or(RoleError::InvalidRole(role.into()))
     }
 
+    /// Creates new [RoleValue] instances from multiple roles without
+    /// performing the validation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRole,
+    ///     RoleValue,
+    /// };
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// let values = RoleValue::from_roles(&[MyRole::Staff, MyRole::Member]);
+    ///
+    /// assert_eq!(
+    ///     values,
+    ///     vec![RoleValue::Role(MyRole::Staff), RoleValue::Role(MyRole::Member)]
+    /// );
+    /// ```
+    ///
+    /// * `roles` - The role variants.
+    pub fn from_roles(roles: &[T]) -> Vec<Self> {
+        roles.iter().copied().map(RoleValue::Role).collect()
+    }
+
     /// Creates a new [RoleValue] instance from an integer value without
     /// performing the validation.
     ///
@@ -130,6 +161,34 @@ where
             .then_some(RoleValue::Raw(value))
             .ok_or(RoleError::InvalidRole(value))
     }
+
+    /// Validates the role value and returns its magnitude.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRole,
+    ///     RoleValue,
+    /// };
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    /// }
+    ///
+    /// let value: RoleValue<MyRole> = RoleValue::Raw(4);
+    ///
+    /// assert_eq!(value.validate().expect("invalid value"), 4);
+    /// ```
+    pub fn validate(&self) -> Result<usize, RoleError> {
+        let mag: usize = (*self).into();
+
+        is_valid_role(mag)
+            .then_some(mag)
+            .ok_or(RoleError::InvalidRole(mag))
+    }
 }
 
 impl<T> From<RoleValue<T>> for usize
@@ -0,0 +1,242 @@
+use crate::{
+    BitRoleImpl,
+    RoleManager,
+    RoleVariant,
+};
+use serde::{
+    de::{
+        Error,
+        SeqAccess,
+        Visitor,
+    },
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+use std::{
+    fmt,
+    marker::PhantomData,
+};
+
+/// Wrapper that (de)serializes a [RoleManager] as its integer value.
+///
+/// # Examples
+///
+/// ```
+/// use bit_roles::{
+///     AsInteger,
+///     BitRole,
+/// };
+///
+/// #[derive(Debug, BitRole, Copy, Clone)]
+/// enum MyRole {
+///     None = 0,
+///     Staff = 1,
+///     Member = 2,
+/// }
+///
+/// let roles = AsInteger(MyRole::from_value(3));
+/// let json = serde_json::to_string(&roles).expect("cannot serialize");
+///
+/// assert_eq!(json, "3");
+/// ```
+#[derive(Debug)]
+pub struct AsInteger<T>(pub RoleManager<T>);
+
+impl<T> Serialize for AsInteger<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0 .0.serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for AsInteger<T>
+where
+    T: BitRoleImpl<T>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = usize::deserialize(deserializer)?;
+        manager_from_value(value).map(AsInteger)
+    }
+}
+
+/// Creates a manager from a deserialized value. When the `strict` feature is
+/// enabled, values containing undeclared roles are rejected with an error
+/// instead of a panic.
+///
+/// * `value` - The deserialized value.
+fn manager_from_value<T, E>(value: usize) -> Result<RoleManager<T>, E>
+where
+    T: BitRoleImpl<T>,
+    E: Error,
+{
+    #[cfg(feature = "strict")]
+    if value & !T::DECLARED_MASK != 0 {
+        return Err(E::custom(crate::RoleError::Undeclared(
+            value & !T::DECLARED_MASK,
+        )));
+    }
+
+    Ok(T::from_value(value))
+}
+
+/// Wrapper that (de)serializes a [RoleManager] as an array of the names of
+/// its roles.
+///
+/// # Examples
+///
+/// ```
+/// use bit_roles::{
+///     AsNames,
+///     BitRole,
+/// };
+///
+/// #[derive(Debug, BitRole, Copy, Clone)]
+/// enum MyRole {
+///     None = 0,
+///     Staff = 1,
+///     Member = 2,
+/// }
+///
+/// let roles = AsNames(MyRole::from_value(3));
+/// let json = serde_json::to_string(&roles).expect("cannot serialize");
+///
+/// assert_eq!(json, r#"["Staff","Member"]"#);
+/// ```
+#[derive(Debug)]
+pub struct AsNames<T>(pub RoleManager<T>);
+
+impl<T> Serialize for AsNames<T>
+where
+    T: RoleVariant + BitRoleImpl<T>,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        T::all_roles()
+            .into_iter()
+            .filter(|role| self.0.has_one(*role))
+            .map(|role| role.name())
+            .collect::<Vec<_>>()
+            .serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for AsNames<T>
+where
+    T: RoleVariant + BitRoleImpl<T>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut manager = T::empty();
+
+        for name in Vec::<String>::deserialize(deserializer)? {
+            let role = T::from_name(&name)
+                .ok_or_else(|| D::Error::custom(format!("unknown role `{name}`")))?;
+
+            manager.add_one(role);
+        }
+
+        Ok(AsNames(manager))
+    }
+}
+
+/// Visitor accepting either an integer value or an array of role names.
+struct RoleManagerVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for RoleManagerVisitor<T>
+where
+    T: RoleVariant + BitRoleImpl<T>,
+{
+    type Value = RoleManager<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an integer or an array of role names")
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        let value = usize::try_from(value)
+            .map_err(|_| E::custom(format!("`{value}` does not fit in `usize`")))?;
+
+        manager_from_value(value)
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        let value =
+            u64::try_from(value).map_err(|_| E::custom(format!("`{value}` is negative")))?;
+
+        self.visit_u64(value)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut manager = T::empty();
+
+        while let Some(name) = seq.next_element::<String>()? {
+            let role = T::from_name(&name)
+                .ok_or_else(|| A::Error::custom(format!("unknown role `{name}`")))?;
+
+            manager.add_one(role);
+        }
+
+        Ok(manager)
+    }
+}
+
+/// Deserializes a [RoleManager] from either its integer value or an array of
+/// the names of its roles. Formats that are not human-readable only accept the
+/// integer value, so that non-self-describing formats are supported.
+///
+/// # Examples
+///
+/// ```
+/// use bit_roles::{
+///     BitRole,
+///     RoleManager,
+/// };
+///
+/// #[derive(Debug, BitRole, Copy, Clone)]
+/// enum MyRole {
+///     None = 0,
+///     Staff = 1,
+///     Member = 2,
+/// }
+///
+/// let from_integer: RoleManager<MyRole> = serde_json::from_str("3").expect("invalid roles");
+/// let from_names: RoleManager<MyRole> =
+///     serde_json::from_str(r#"["Staff","Member"]"#).expect("invalid roles");
+///
+/// assert_eq!(from_integer, from_names);
+/// ```
+impl<'de, T> Deserialize<'de> for RoleManager<T>
+where
+    T: RoleVariant + BitRoleImpl<T>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(RoleManagerVisitor(PhantomData))
+        } else {
+            deserializer.deserialize_u64(RoleManagerVisitor(PhantomData))
+        }
+    }
+}
@@ -1,9 +1,8 @@
 use crate::{
-    utils::{
-        is_valid_role,
-        negate,
-    },
+    utils::negate,
+    BitRoleImpl,
     RoleError,
+    RoleManager,
     RoleValue,
     RoleVariant,
 };
@@ -91,11 +90,17 @@ where
     ///
     /// * `role` - The role value to validate.
     fn validate_role(&self, role: RoleValue<T>) -> Result<usize, RoleError> {
-        let mag: usize = role.into();
+        role.validate()
+    }
 
-        is_valid_role(mag)
-            .then_some(mag)
-            .ok_or(RoleError::InvalidRole(mag))
+    /// Validates the magnitudes of multiple role values and returns their
+    /// combined value.
+    ///
+    /// * `roles` - The role values to validate.
+    fn validate_roles(&self, roles: Vec<RoleValue<T>>) -> Result<usize, RoleError> {
+        roles
+            .into_iter()
+            .try_fold(0, |value, role| Ok(value | self.validate_role(role)?))
     }
 
     /// Converts a vector of roles to a vector of equivalent [RoleValue]
@@ -103,7 +108,7 @@ where
     ///
     /// * `roles` - The roles to convert.
     fn to_role_values(&self, roles: Vec<T>) -> Vec<RoleValue<T>> {
-        roles.into_iter().map(RoleValue::Role).collect::<Vec<_>>()
+        RoleValue::from_roles(&roles)
     }
 
     /// Validates and adds a single role value to the manager instance. This is
@@ -197,6 +202,107 @@ where
         Ok(self)
     }
 
+    /// Validates and adds multiple role values to the manager instance. Unlike
+    /// [try_add_all], the manager is left untouched if any of the roles is
+    /// invalid.
+    ///
+    /// [try_add_all]: RoleManagerUnchecked::try_add_all
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRoleUnchecked,
+    ///     RoleValue,
+    /// };
+    ///
+    /// #[derive(Debug, BitRoleUnchecked, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// impl Into<usize> for MyRole {
+    ///     fn into(self) -> usize {
+    ///         self as usize
+    ///     }
+    /// }
+    ///
+    /// let mut roles = MyRole::empty();
+    ///
+    /// // Try adding a batch containing an invalid role.
+    /// let result = roles.try_add_all_atomic(vec![
+    ///     RoleValue::Role(MyRole::Staff),
+    ///     RoleValue::Raw(3),
+    /// ]);
+    ///
+    /// assert!(result.is_err());
+    /// assert_eq!(roles.get_value(), 0);
+    /// ```
+    ///
+    /// * `roles` - The role values to add to the manager.
+    pub fn try_add_all_atomic(
+        &mut self,
+        roles: Vec<RoleValue<T>>,
+    ) -> Result<&mut Self, RoleError> {
+        let value = self.validate_roles(roles)?;
+        self.0.bitor_assign(value);
+
+        Ok(self)
+    }
+
+    /// Validates and adds multiple role values to the manager instance,
+    /// skipping the invalid ones. Returns the number of roles applied along
+    /// with the errors for the skipped roles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRoleUnchecked,
+    ///     RoleValue,
+    /// };
+    ///
+    /// #[derive(Debug, BitRoleUnchecked, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// impl Into<usize> for MyRole {
+    ///     fn into(self) -> usize {
+    ///         self as usize
+    ///     }
+    /// }
+    ///
+    /// let mut roles = MyRole::empty();
+    ///
+    /// // Add a batch containing an invalid role.
+    /// let (applied, errors) =
+    ///     roles.add_all_lossy(&[RoleValue::Role(MyRole::Staff), RoleValue::Raw(3)]);
+    ///
+    /// assert_eq!(applied, 1);
+    /// assert_eq!(errors.len(), 1);
+    /// assert!(roles.has_one(MyRole::Staff));
+    /// ```
+    ///
+    /// * `roles` - The role values to add to the manager.
+    pub fn add_all_lossy(&mut self, roles: &[RoleValue<T>]) -> (usize, Vec<RoleError>) {
+        let mut applied = 0;
+        let mut errors = Vec::new();
+
+        for role in roles {
+            match self.try_add_one(*role) {
+                Ok(_) => applied += 1,
+                Err(err) => errors.push(err),
+            }
+        }
+
+        (applied, errors)
+    }
+
     /// Validates and removes a single role value from the manager instance.
     /// This is a non-panicking equivalent of the [remove_one] method.
     ///
@@ -288,6 +394,57 @@ where
         Ok(self)
     }
 
+    /// Validates and removes multiple role values from the manager instance.
+    /// Unlike [try_remove_all], the manager is left untouched if any of the
+    /// roles is invalid.
+    ///
+    /// [try_remove_all]: RoleManagerUnchecked::try_remove_all
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRoleUnchecked,
+    ///     RoleValue,
+    /// };
+    ///
+    /// #[derive(Debug, BitRoleUnchecked, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// impl Into<usize> for MyRole {
+    ///     fn into(self) -> usize {
+    ///         self as usize
+    ///     }
+    /// }
+    ///
+    /// let mut roles = MyRole::empty();
+    /// roles.add_all(vec![MyRole::Staff, MyRole::Member]);
+    ///
+    /// // Try removing a batch containing an invalid role.
+    /// let result = roles.try_remove_all_atomic(vec![
+    ///     RoleValue::Role(MyRole::Staff),
+    ///     RoleValue::Raw(3),
+    /// ]);
+    ///
+    /// assert!(result.is_err());
+    /// assert!(roles.has_all(vec![MyRole::Staff, MyRole::Member]));
+    /// ```
+    ///
+    /// * `roles` - The role values to remove from the manager.
+    pub fn try_remove_all_atomic(
+        &mut self,
+        roles: Vec<RoleValue<T>>,
+    ) -> Result<&mut Self, RoleError> {
+        let value = self.validate_roles(roles)?;
+        self.0.bitand_assign(!value);
+
+        Ok(self)
+    }
+
     /// Validates and checks whether a single role is assigned to the manager
     /// instance. This is a non-panicking equivalent of the [has_one] method.
     ///
@@ -647,8 +804,52 @@ where
     ///
     /// * `roles` - The roles to add to the manager.
     pub fn add_all(&mut self, roles: Vec<T>) -> &mut Self {
-        self.try_add_all(self.to_role_values(roles))
-            .expect("`roles` contain invalid values")
+        self.add_iter(roles)
+    }
+
+    /// Adds roles from an iterator to the manager instance. Panics if any of
+    /// the roles is invalid. Use [try_add_all] as a non-panicking equivalent.
+    ///
+    /// [try_add_all]: RoleManagerUnchecked::try_add_all
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRoleUnchecked,
+    ///     RoleValue,
+    /// };
+    ///
+    /// #[derive(Debug, BitRoleUnchecked, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// impl Into<usize> for MyRole {
+    ///     fn into(self) -> usize {
+    ///         self as usize
+    ///     }
+    /// }
+    ///
+    /// let source = [MyRole::None, MyRole::Staff, MyRole::Member];
+    /// let mut roles = MyRole::empty();
+    ///
+    /// // Add the roles from an iterator to the manager.
+    /// roles.add_iter(source.iter().copied().skip(1));
+    ///
+    /// assert!(roles.has_all(vec![MyRole::Staff, MyRole::Member]));
+    /// ```
+    ///
+    /// * `roles` - The roles to add to the manager.
+    pub fn add_iter(&mut self, roles: impl IntoIterator<Item = T>) -> &mut Self {
+        for role in roles {
+            self.try_add_one(RoleValue::Role(role))
+                .expect("`roles` contain invalid values");
+        }
+
+        self
     }
 
     /// Removes a single role from the manager instance. Panics if the role is
@@ -727,8 +928,54 @@ where
     ///
     /// * `roles` - The roles to remove from the manager.
     pub fn remove_all(&mut self, roles: Vec<T>) -> &mut Self {
-        self.try_remove_all(self.to_role_values(roles))
-            .expect("`roles` contain invalid values")
+        self.remove_iter(roles)
+    }
+
+    /// Removes roles from an iterator from the manager instance. Panics if any
+    /// of the roles is invalid. Use [try_remove_all] as a non-panicking
+    /// equivalent.
+    ///
+    /// [try_remove_all]: RoleManagerUnchecked::try_remove_all
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRoleUnchecked,
+    ///     RoleValue,
+    /// };
+    ///
+    /// #[derive(Debug, BitRoleUnchecked, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// impl Into<usize> for MyRole {
+    ///     fn into(self) -> usize {
+    ///         self as usize
+    ///     }
+    /// }
+    ///
+    /// let source = [MyRole::None, MyRole::Staff, MyRole::Member];
+    /// let mut roles = MyRole::empty();
+    /// roles.add_all(vec![MyRole::Staff, MyRole::Member]);
+    ///
+    /// // Remove the roles from an iterator from the manager.
+    /// roles.remove_iter(source.iter().copied().skip(1));
+    ///
+    /// assert!(roles.not_any(vec![MyRole::Staff, MyRole::Member]));
+    /// ```
+    ///
+    /// * `roles` - The roles to remove from the manager.
+    pub fn remove_iter(&mut self, roles: impl IntoIterator<Item = T>) -> &mut Self {
+        for role in roles {
+            self.try_remove_one(RoleValue::Role(role))
+                .expect("`roles` contain invalid values");
+        }
+
+        self
     }
 
     /// Checks whether a single role is assigned to the manager instance. Panics
@@ -766,6 +1013,7 @@ where
     /// ```
     ///
     /// * `role` - The role to check against the manager.
+    #[must_use]
     pub fn has_one(&self, role: T) -> bool {
         self.try_has_one(RoleValue::Role(role))
             .expect("`role` is invalid")
@@ -810,6 +1058,7 @@ where
     /// ```
     ///
     /// * `roles` - The roles to check against the manager.
+    #[must_use]
     pub fn has_all(&self, roles: Vec<T>) -> bool {
         self.try_has_all(self.to_role_values(roles))
             .expect("`roles` contain invalid values")
@@ -854,6 +1103,7 @@ where
     /// ```
     ///
     /// * `roles` - The roles to check against the manager.
+    #[must_use]
     pub fn has_any(&self, roles: Vec<T>) -> bool {
         self.try_has_any(self.to_role_values(roles))
             .expect("`roles` contain invalid values")
@@ -894,6 +1144,7 @@ where
     /// ```
     ///
     /// * `role` - The role to check against the manager.
+    #[must_use]
     pub fn not_one(&self, role: T) -> bool {
         self.try_not_one(RoleValue::Role(role))
             .expect("`role` is invalid")
@@ -935,6 +1186,7 @@ where
     /// ```
     ///
     /// * `roles` - The roles to check against the manager.
+    #[must_use]
     pub fn not_all(&self, roles: Vec<T>) -> bool {
         self.try_not_all(self.to_role_values(roles))
             .expect("`roles` contain invalid values")
@@ -976,6 +1228,7 @@ where
     /// ```
     ///
     /// * `roles` - The roles to check against the manager.
+    #[must_use]
     pub fn not_any(&self, roles: Vec<T>) -> bool {
         self.try_not_any(self.to_role_values(roles))
             .expect("`roles` contain invalid values")
@@ -1010,9 +1263,346 @@ where
     ///
     /// assert_eq!(value, 0);
     /// ```
+    #[must_use]
     pub fn get_value(&self) -> usize {
         self.0
     }
+
+    /// Returns an iterator over the positions of the bits set in the manager
+    /// instance, from the least significant bit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRoleUnchecked,
+    ///     RoleValue,
+    /// };
+    ///
+    /// #[derive(Debug, BitRoleUnchecked, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    /// }
+    ///
+    /// impl Into<usize> for MyRole {
+    ///     fn into(self) -> usize {
+    ///         self as usize
+    ///     }
+    /// }
+    ///
+    /// let roles = MyRole::from_value(5);
+    ///
+    /// assert_eq!(roles.bits().collect::<Vec<_>>(), vec![0, 2]);
+    /// ```
+    pub fn bits(&self) -> impl Iterator<Item = u32> {
+        let mut value = self.0;
+
+        std::iter::from_fn(move || {
+            (value != 0).then(|| {
+                let position = value.trailing_zeros();
+                value &= value - 1;
+                position
+            })
+        })
+    }
+
+    /// Narrows the manager instance to the roles also assigned to the other
+    /// manager.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRoleUnchecked,
+    ///     RoleValue,
+    /// };
+    ///
+    /// #[derive(Debug, BitRoleUnchecked, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    ///     Guest = 4,
+    /// }
+    ///
+    /// impl Into<usize> for MyRole {
+    ///     fn into(self) -> usize {
+    ///         self as usize
+    ///     }
+    /// }
+    ///
+    /// let mut roles = MyRole::from_value(7);
+    /// let allowed = MyRole::from_value(MyRole::Member.into());
+    ///
+    /// roles.intersect_with(&allowed);
+    ///
+    /// assert_eq!(roles.get_value(), MyRole::Member.into());
+    /// ```
+    ///
+    /// * `other` - The manager to intersect with.
+    pub fn intersect_with(&mut self, other: &Self) -> &mut Self {
+        self.0.bitand_assign(other.0);
+        self
+    }
+
+    /// Adds every role assigned to the other manager to the manager instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRoleUnchecked,
+    ///     RoleValue,
+    /// };
+    ///
+    /// #[derive(Debug, BitRoleUnchecked, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    ///     Guest = 4,
+    /// }
+    ///
+    /// impl Into<usize> for MyRole {
+    ///     fn into(self) -> usize {
+    ///         self as usize
+    ///     }
+    /// }
+    ///
+    /// let mut roles = MyRole::from_value(MyRole::Staff.into());
+    /// let granted = MyRole::from_value(6);
+    ///
+    /// roles.union_with(&granted);
+    ///
+    /// assert_eq!(roles.get_value(), 7);
+    /// ```
+    ///
+    /// * `other` - The manager to add the roles from.
+    pub fn union_with(&mut self, other: &Self) -> &mut Self {
+        self.0.bitor_assign(other.0);
+        self
+    }
+
+    /// Removes every role assigned to the other manager from the manager
+    /// instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRoleUnchecked,
+    ///     RoleValue,
+    /// };
+    ///
+    /// #[derive(Debug, BitRoleUnchecked, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    ///     Guest = 4,
+    /// }
+    ///
+    /// impl Into<usize> for MyRole {
+    ///     fn into(self) -> usize {
+    ///         self as usize
+    ///     }
+    /// }
+    ///
+    /// let mut roles = MyRole::from_value(7);
+    /// let revoked = MyRole::from_value(6);
+    ///
+    /// roles.subtract(&revoked);
+    ///
+    /// assert_eq!(roles.get_value(), MyRole::Staff.into());
+    /// ```
+    ///
+    /// * `other` - The manager to remove the roles of.
+    pub fn subtract(&mut self, other: &Self) -> &mut Self {
+        self.0.bitand_assign(!other.0);
+        self
+    }
+
+    /// Converts the manager instance into a [RoleManager] with compile-time
+    /// value checks. Returns an error holding the undeclared bits if the value
+    /// contains roles that are not declared by the role enum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRole,
+    ///     RoleManagerUnchecked,
+    /// };
+    ///
+    /// #[derive(Debug, BitRole, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    /// }
+    ///
+    /// let unchecked = RoleManagerUnchecked::<MyRole>(1, std::marker::PhantomData);
+    /// let roles = unchecked.into_checked().expect("undeclared roles");
+    ///
+    /// assert!(roles.has_one(MyRole::Staff));
+    /// ```
+    pub fn into_checked(self) -> Result<RoleManager<T>, RoleError>
+    where
+        T: BitRoleImpl<T>,
+    {
+        let manager = RoleManager(self.0, PhantomData);
+
+        if manager.is_valid() {
+            Ok(manager)
+        } else {
+            Err(RoleError::Undeclared(self.0 & !T::DECLARED_MASK))
+        }
+    }
+
+    /// Creates a new [RoleManagerUnchecked] instance from a slice of role
+    /// values, returning it along with the number of bits that were already
+    /// set when re-added. Returns an error if any of the role values is
+    /// invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::{
+    ///     BitRoleUnchecked,
+    ///     RoleManagerUnchecked,
+    ///     RoleValue,
+    /// };
+    ///
+    /// #[derive(Debug, BitRoleUnchecked, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// impl Into<usize> for MyRole {
+    ///     fn into(self) -> usize {
+    ///         self as usize
+    ///     }
+    /// }
+    ///
+    /// let (roles, duplicates) = RoleManagerUnchecked::try_from_values_counted(&[
+    ///     RoleValue::Role(MyRole::Staff),
+    ///     RoleValue::Raw(1),
+    ///     RoleValue::Role(MyRole::Member),
+    /// ])
+    /// .expect("invalid role value");
+    ///
+    /// assert_eq!(roles.get_value(), 3);
+    /// assert_eq!(duplicates, 1);
+    /// ```
+    ///
+    /// * `values` - The role values to add to the manager.
+    pub fn try_from_values_counted(values: &[RoleValue<T>]) -> Result<(Self, usize), RoleError> {
+        let mut manager = Self(0, PhantomData);
+        let mut duplicates = 0;
+
+        for value in values {
+            let value = value.validate()?;
+
+            if manager.0.bitand(value) != 0 {
+                duplicates += 1;
+            }
+
+            manager.0.bitor_assign(value);
+        }
+
+        Ok((manager, duplicates))
+    }
+
+    /// Returns the number of roles assigned to the manager instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRoleUnchecked;
+    ///
+    /// #[derive(Debug, BitRoleUnchecked, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// impl Into<usize> for MyRole {
+    ///     fn into(self) -> usize {
+    ///         self as usize
+    ///     }
+    /// }
+    ///
+    /// let roles = MyRole::from_value(3);
+    ///
+    /// assert_eq!(roles.count(), 2);
+    /// ```
+    #[must_use]
+    pub fn count(&self) -> u32 {
+        self.0.count_ones()
+    }
+
+    /// Checks whether no roles are assigned to the manager instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRoleUnchecked;
+    ///
+    /// #[derive(Debug, BitRoleUnchecked, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// impl Into<usize> for MyRole {
+    ///     fn into(self) -> usize {
+    ///         self as usize
+    ///     }
+    /// }
+    ///
+    /// let roles = MyRole::empty();
+    ///
+    /// assert!(roles.is_empty());
+    /// ```
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Removes every role from the manager instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_roles::BitRoleUnchecked;
+    ///
+    /// #[derive(Debug, BitRoleUnchecked, Copy, Clone)]
+    /// enum MyRole {
+    ///     None = 0,
+    ///     Staff = 1,
+    ///     Member = 2,
+    /// }
+    ///
+    /// impl Into<usize> for MyRole {
+    ///     fn into(self) -> usize {
+    ///         self as usize
+    ///     }
+    /// }
+    ///
+    /// let mut roles = MyRole::from_value(3);
+    ///
+    /// roles.clear();
+    ///
+    /// assert!(roles.is_empty());
+    /// ```
+    pub fn clear(&mut self) -> &mut Self {
+        self.0 = 0;
+        self
+    }
 }
 
 impl<T> PartialEq<Self> for RoleManagerUnchecked<T> {
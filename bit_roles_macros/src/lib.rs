@@ -5,16 +5,46 @@
 
 use proc_macro::TokenStream;
 use proc_macro2::Span;
-use quote::quote;
+use quote::{
+    format_ident,
+    quote,
+};
 use syn::{
     parse_macro_input,
+    punctuated::Punctuated,
+    Attribute,
     Data,
     DeriveInput,
     Expr,
+    Ident,
     Lit,
+    LitStr,
+    Meta,
+    Token,
     Variant,
 };
 
+/// The options provided to an enum variant using the `#[bit_role(...)]`
+/// attribute.
+#[derive(Default)]
+struct VariantOptions {
+    /// The name of the group the variant belongs to.
+    group: Option<String>,
+    /// The human-readable description of the variant.
+    desc: Option<String>,
+    /// Whether the variant is excluded from the declared roles.
+    skip: bool,
+}
+
+/// The options provided to an enum using the `#[bit_role(...)]` attribute.
+#[derive(Default)]
+struct EnumOptions {
+    /// The named bundles of variants.
+    bundles: Vec<(Ident, Vec<Ident>)>,
+    /// Whether the zero variant must be declared first.
+    strict_zero: bool,
+}
+
 /// Returns a new [syn::Error] with the provided error message.
 ///
 /// * `message` - The error message.
@@ -22,11 +52,157 @@ fn throw_error(message: &str) -> syn::Error {
     syn::Error::new(Span::call_site(), message)
 }
 
-/// Validates the discriminant of an enum variant.
+/// Parses the `#[bit_role(...)]` attributes of an enum variant.
+///
+/// * `variant` - The enum variant.
+fn parse_variant_options(variant: &Variant) -> Result<VariantOptions, syn::Error> {
+    let mut options = VariantOptions::default();
+
+    for attr in variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("bit_role"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("group") {
+                let group = meta.value()?.parse::<LitStr>()?;
+
+                if syn::parse_str::<Ident>(&group.value().to_uppercase()).is_err() {
+                    return Err(syn::Error::new(
+                        group.span(),
+                        format!("`{}` is not a valid group name", group.value()),
+                    ));
+                }
+
+                let mask_name = format!("{}_MASK", group.value().to_uppercase());
+
+                if RESERVED_CONSTANT_NAMES.contains(&mask_name.as_str()) {
+                    return Err(syn::Error::new(
+                        group.span(),
+                        format!(
+                            "the mask constant of the `{}` group collides with a `BitRoleImpl` constant",
+                            group.value()
+                        ),
+                    ));
+                }
+
+                options.group = Some(group.value());
+                Ok(())
+            } else if meta.path.is_ident("desc") {
+                options.desc = Some(meta.value()?.parse::<LitStr>()?.value());
+                Ok(())
+            } else if meta.path.is_ident("skip") {
+                options.skip = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported `bit_role` attribute"))
+            }
+        })?;
+    }
+
+    if options.skip && options.group.is_some() {
+        return Err(syn::Error::new_spanned(
+            &variant.ident,
+            format!(
+                "skipped variant `{}` cannot belong to a group",
+                variant.ident
+            ),
+        ));
+    }
+
+    Ok(options)
+}
+
+/// Parses the `#[bit_role(...)]` attributes of an enum.
+///
+/// * `attrs` - The attributes of the enum.
+fn parse_enum_options(attrs: &[Attribute]) -> Result<EnumOptions, syn::Error> {
+    let mut options = EnumOptions::default();
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("bit_role")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("bundle") {
+                meta.parse_nested_meta(|bundle| {
+                    let ident = bundle.path.require_ident()?.clone();
+
+                    if options.bundles.iter().any(|(name, _)| *name == ident) {
+                        return Err(bundle.error(format!("duplicate `{ident}` bundle")));
+                    }
+
+                    let input = bundle.value()?;
+                    let content;
+                    syn::bracketed!(content in input);
+                    let variants = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+
+                    options
+                        .bundles
+                        .push((ident, variants.into_iter().collect()));
+                    Ok(())
+                })
+            } else if meta.path.is_ident("strict_zero") {
+                options.strict_zero = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported `bit_role` attribute"))
+            }
+        })?;
+    }
+
+    Ok(options)
+}
+
+/// The names of the associated functions of a role enum that bundle
+/// constructors cannot shadow.
+const RESERVED_BUNDLE_NAMES: &[&str] = &[
+    "all",
+    "all_roles",
+    "describe",
+    "empty",
+    "from_name",
+    "from_names",
+    "from_value",
+    "into",
+    "is_valid",
+    "name",
+    "try_from",
+    "value",
+];
+
+/// The names of the associated constants of a role enum that group mask
+/// constants cannot shadow.
+const RESERVED_CONSTANT_NAMES: &[&str] = &["DECLARED_BITS", "DECLARED_MASK"];
+
+/// Converts a `PascalCase` identifier into `snake_case`. A run of uppercase
+/// letters, such as an acronym, is treated as a single word.
+///
+/// * `ident` - The identifier to convert.
+fn to_snake_case(ident: &Ident) -> Ident {
+    let chars = ident.to_string().chars().collect::<Vec<_>>();
+    let mut snake = String::new();
+
+    for (index, char) in chars.iter().enumerate() {
+        if char.is_uppercase() && index != 0 {
+            let previous = chars[index - 1];
+            let next_is_lowercase = chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+
+            if !previous.is_uppercase() || next_is_lowercase {
+                snake.push('_');
+            }
+        }
+
+        snake.extend(char.to_lowercase());
+    }
+
+    format_ident!("{}", snake)
+}
+
+/// Validates the discriminant of an enum variant and returns its value. Returns
+/// `None` for non-literal discriminants, such as constant expressions, whose
+/// validation is deferred to a generated compile-time assertion.
 ///
 /// * `variant` - The enum variant.
 /// * `enum_name` - The literal name of the enum.
-fn validate_enum_variant(variant: Variant, enum_name: &str) -> Result<(), syn::Error> {
+fn validate_enum_variant(variant: Variant, enum_name: &str) -> Result<Option<usize>, syn::Error> {
     let variant_name = variant.ident;
     let (_, expression) = variant.discriminant.ok_or(throw_error(
         format!(
@@ -50,7 +226,7 @@ fn validate_enum_variant(variant: Variant, enum_name: &str) -> Result<(), syn::E
                             .as_str(),
                     ))
                 } else {
-                    Ok(())
+                    Ok(Some(value))
                 }
             }
             _ => Err(throw_error(
@@ -60,20 +236,59 @@ fn validate_enum_variant(variant: Variant, enum_name: &str) -> Result<(), syn::E
                 .as_str(),
             )),
         },
-        _ => Err(throw_error(
-            format!(
-                "`{variant_name}` in the `{enum_name}` enum must have a literal RHS expression"
-            )
-            .as_str(),
-        )),
+        _ => Ok(None),
+    }
+}
+
+/// Returns the name and the largest value of the integer representation
+/// specified using the `#[repr(...)]` attribute, if any.
+///
+/// * `attrs` - The attributes of the enum.
+fn parse_repr(attrs: &[Attribute]) -> Result<Option<(String, u128)>, syn::Error> {
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("repr")) {
+        let metas = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+
+        for meta in metas {
+            let Meta::Path(path) = meta else {
+                continue;
+            };
+
+            let Some(ident) = path.get_ident() else {
+                continue;
+            };
+
+            let max = match ident.to_string().as_str() {
+                "u8" => u8::MAX as u128,
+                "u16" => u16::MAX as u128,
+                "u32" => u32::MAX as u128,
+                "u64" => u64::MAX as u128,
+                "u128" => u128::MAX,
+                "usize" => usize::MAX as u128,
+                "i8" => i8::MAX as u128,
+                "i16" => i16::MAX as u128,
+                "i32" => i32::MAX as u128,
+                "i64" => i64::MAX as u128,
+                "i128" => i128::MAX as u128,
+                "isize" => isize::MAX as u128,
+                _ => continue,
+            };
+
+            return Ok(Some((ident.to_string(), max)));
+        }
     }
+
+    Ok(None)
 }
 
 /// Bit role manager with compile-time value checking. Useful when you have
 /// a simple role enum definition and do not wish to work with raw integer role
 /// values. Each variant of your role enum must return a valid role value that
 /// is either zero or a power of two. Your role enum must also derive the [Copy]
-/// and [Clone] traits.
+/// and [Clone] traits, and cannot have generic parameters.
+///
+/// Role values must fit in `usize`. As distinct roles occupy distinct bits of
+/// a `usize`, more than `usize::BITS` of them can never be declared, so this
+/// is the only width check the derive needs.
 ///
 /// Check the `BitRoleUnchecked` variant if you need to work with raw integer
 /// role values or you have a complex role enum definition.
@@ -100,6 +315,155 @@ fn validate_enum_variant(variant: Variant, enum_name: &str) -> Result<(), syn::E
 /// assert!(roles.has_one(Permission::SendMessage));
 /// ```
 ///
+/// The derive also implements `TryFrom<usize>` for your role enum, which
+/// decodes a single role value back into its variant.
+///
+/// ```
+/// use bit_roles::BitRole;
+///
+/// #[derive(Debug, BitRole, Copy, Clone)]
+/// enum Permission {
+///     None = 0,
+///     SendMessage = 1,
+///     EditMessage = 2,
+/// }
+///
+/// let role = Permission::try_from(2).expect("unknown role value");
+///
+/// assert!(matches!(role, Permission::EditMessage));
+/// assert!(Permission::try_from(4).is_err());
+/// ```
+///
+/// Variants can be grouped into categories using the `#[bit_role(group =
+/// "...")]` attribute. A mask constant named after each group is generated
+/// for your role enum.
+///
+/// ```
+/// use bit_roles::BitRole;
+///
+/// #[derive(Debug, BitRole, Copy, Clone)]
+/// enum Permission {
+///     None = 0,
+///     #[bit_role(group = "content")]
+///     SendMessage = 1,
+///     #[bit_role(group = "content")]
+///     EditMessage = 2,
+///     BanMember = 4,
+/// }
+///
+/// let roles = Permission::from_value(7);
+///
+/// // Extract the roles in the `content` group.
+/// let content_roles = roles.masked(Permission::CONTENT_MASK);
+///
+/// assert_eq!(content_roles.get_value(), 3);
+/// ```
+///
+/// Frequently used combinations of roles can be declared as bundles using the
+/// `#[bit_role(bundle(...))]` attribute on the enum. A constructor named after
+/// each bundle is generated for your role enum, in `snake_case` with acronyms
+/// kept as a single word. Bundles cannot be named after the functions of the
+/// role enum, such as `all` or `empty`.
+///
+/// ```
+/// use bit_roles::BitRole;
+///
+/// #[derive(Debug, BitRole, Copy, Clone)]
+/// #[bit_role(bundle(Editor = [SendMessage, EditMessage]))]
+/// enum Permission {
+///     None = 0,
+///     SendMessage = 1,
+///     EditMessage = 2,
+///     BanMember = 4,
+/// }
+///
+/// let roles = Permission::editor();
+///
+/// assert_eq!(roles.get_value(), 3);
+/// ```
+///
+/// The `#[bit_role(strict_zero)]` attribute on the enum requires the zero
+/// variant, if any, to be declared first. A zero variant declared among the
+/// roles is likely mistaken for a grantable role, even though adding it is a
+/// no-op.
+///
+/// ```compile_fail
+/// use bit_roles::BitRole;
+///
+/// // This should not compile.
+/// #[derive(Debug, BitRole, Copy, Clone)]
+/// #[bit_role(strict_zero)]
+/// enum Permission {
+///     SendMessage = 1,
+///     None = 0,
+/// }
+/// ```
+///
+/// Variants that do not represent a role can be excluded using the
+/// `#[bit_role(skip)]` attribute. Skipped variants are not validated, and are
+/// left out of the declared roles and the name lookups. They convert to the
+/// empty role, so adding one to a manager is a no-op.
+///
+/// ```
+/// use bit_roles::BitRole;
+///
+/// #[derive(Debug, BitRole, Copy, Clone)]
+/// enum Permission {
+///     None = 0,
+///     SendMessage = 1,
+///     EditMessage = 2,
+///     #[bit_role(skip)]
+///     Internal = 3,
+/// }
+///
+/// assert_eq!(Permission::DECLARED_MASK, 3);
+/// assert!(Permission::from_name("Internal").is_none());
+/// assert_eq!(
+///     Permission::empty()
+///         .add_one(Permission::Internal)
+///         .get_value(),
+///     0
+/// );
+/// ```
+///
+/// Variants can be documented using the `#[bit_role(desc = "...")]`
+/// attribute. The description is returned by the generated `describe` method,
+/// which falls back to the variant name.
+///
+/// ```
+/// use bit_roles::BitRole;
+///
+/// #[derive(Debug, BitRole, Copy, Clone)]
+/// enum Permission {
+///     None = 0,
+///     #[bit_role(desc = "Can send messages")]
+///     SendMessage = 1,
+///     EditMessage = 2,
+/// }
+///
+/// assert_eq!(Permission::SendMessage.describe(), "Can send messages");
+/// assert_eq!(Permission::EditMessage.describe(), "EditMessage");
+/// ```
+///
+/// Discriminants can also be defined using constant expressions. These are
+/// validated using compile-time assertions instead.
+///
+/// ```
+/// use bit_roles::BitRole;
+///
+/// const SEND_MESSAGE: isize = 1;
+/// const EDIT_MESSAGE: isize = SEND_MESSAGE << 1;
+///
+/// #[derive(Debug, BitRole, Copy, Clone)]
+/// enum Permission {
+///     None = 0,
+///     SendMessage = SEND_MESSAGE,
+///     EditMessage = EDIT_MESSAGE,
+/// }
+///
+/// assert_eq!(Permission::DECLARED_MASK, 3);
+/// ```
+///
 /// A compile-time error will be generated if any of the enum variant returns
 /// value that is neither zero nor a power of two.
 ///
@@ -113,7 +477,7 @@ fn validate_enum_variant(variant: Variant, enum_name: &str) -> Result<(), syn::E
 ///     InvalidRole = 5,
 /// }
 /// ```
-#[proc_macro_derive(BitRole)]
+#[proc_macro_derive(BitRole, attributes(bit_role))]
 pub fn derive_bit_role(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
@@ -123,33 +487,366 @@ pub fn derive_bit_role(input: TokenStream) -> TokenStream {
             let enum_name = name.to_string();
             let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
+            // Role values are derived using `as` casts, which are only valid for
+            // field-less enums without generic parameters.
+            if !input.generics.params.is_empty() {
+                return throw_error(
+                    format!("`BitRole` cannot be derived for the generic `{enum_name}` enum")
+                        .as_str(),
+                )
+                .to_compile_error()
+                .into();
+            }
+
+            let mut variants = Vec::new();
+            let mut groups: Vec<(String, usize, Vec<proc_macro2::TokenStream>)> = Vec::new();
+            let mut describe_arms = Vec::new();
+            let mut skipped = Vec::new();
+
             // Validate enum variant discriminants.
             for variant in value.variants.clone() {
-                match validate_enum_variant(variant, &enum_name) {
-                    Ok(_) => {}
+                let ident = variant.ident.clone();
+                let options = match parse_variant_options(&variant) {
+                    Ok(options) => options,
                     Err(err) => return err.to_compile_error().into(),
+                };
+
+                let description = options.desc.unwrap_or_else(|| ident.to_string());
+                describe_arms.push(quote! { #name::#ident => #description, });
+
+                // Skipped variants are excluded from validation and the declared roles.
+                if options.skip {
+                    skipped.push(ident);
+                    continue;
+                }
+
+                let value = match validate_enum_variant(variant, &enum_name) {
+                    Ok(value) => value,
+                    Err(err) => return err.to_compile_error().into(),
+                };
+
+                if let Some(group) = options.group {
+                    let index = match groups.iter().position(|(name, ..)| *name == group) {
+                        Some(index) => index,
+                        None => {
+                            // Group names that only differ in case share the same mask constant.
+                            if let Some((other, ..)) = groups
+                                .iter()
+                                .find(|(name, ..)| name.to_uppercase() == group.to_uppercase())
+                            {
+                                return syn::Error::new_spanned(
+                                    &ident,
+                                    format!(
+                                        "[`{ident}`]: the `{group}` group collides with the `{other}` group"
+                                    ),
+                                )
+                                .to_compile_error()
+                                .into();
+                            }
+
+                            groups.push((group, 0, Vec::new()));
+                            groups.len() - 1
+                        }
+                    };
+
+                    let (_, mask, deferred) = &mut groups[index];
+
+                    match value {
+                        Some(value) => *mask |= value,
+                        None => deferred.push(quote! { (#name::#ident as usize) }),
+                    }
+                }
+
+                variants.push((ident, value));
+            }
+
+            // Ensure at most one variant represents the empty role.
+            if let Some((ident, _)) = variants
+                .iter()
+                .filter(|(_, value)| *value == Some(0))
+                .nth(1)
+            {
+                return throw_error(
+                    format!(
+                        "[`{ident}`]: only one variant in the `{enum_name}` enum can have a zero discriminant"
+                    )
+                    .as_str(),
+                )
+                .to_compile_error()
+                .into();
+            }
+
+            // Ensure the integer representation can hold every discriminant.
+            match parse_repr(&input.attrs) {
+                Ok(Some((repr, max))) => {
+                    for (ident, value) in variants
+                        .iter()
+                        .filter_map(|(ident, value)| value.map(|value| (ident, value)))
+                    {
+                        if value as u128 > max {
+                            return throw_error(
+                                format!(
+                                    "[`{ident}`]: `{value}` does not fit in the `{repr}` representation of the `{enum_name}` enum"
+                                )
+                                .as_str(),
+                            )
+                            .to_compile_error()
+                            .into();
+                        }
+                    }
+                }
+                Ok(None) => {}
+                Err(err) => return err.to_compile_error().into(),
+            }
+
+            // Constructors for each of the variant bundles.
+            let options = match parse_enum_options(&input.attrs) {
+                Ok(options) => options,
+                Err(err) => return err.to_compile_error().into(),
+            };
+
+            // Ensure the zero variant is declared first, as a zero variant declared
+            // among the roles is likely mistaken for a grantable role.
+            let mut zero_assertions = Vec::new();
+
+            if options.strict_zero {
+                for (ident, value) in variants.iter().skip(1) {
+                    let message = format!(
+                        "[`{ident}`]: the zero variant of the `{enum_name}` enum must be declared first"
+                    );
+
+                    match value {
+                        Some(0) => return throw_error(&message).to_compile_error().into(),
+                        Some(_) => {}
+                        None => zero_assertions.push(quote! {
+                            const _: () = assert!(#name::#ident as usize != 0, #message);
+                        }),
+                    }
+                }
+            }
+
+            let mut bundle_constructors = Vec::new();
+            let mut constructor_names: Vec<(Ident, &Ident)> = Vec::new();
+
+            for (bundle, members) in &options.bundles {
+                let mut mask = 0;
+                let mut deferred = Vec::new();
+
+                for member in members {
+                    if skipped.contains(member) {
+                        return syn::Error::new(
+                            member.span(),
+                            format!(
+                                "[`{bundle}`]: skipped variant `{member}` cannot belong to a bundle"
+                            ),
+                        )
+                        .to_compile_error()
+                        .into();
+                    }
+
+                    match variants.iter().find(|(ident, _)| ident == member) {
+                        Some((_, Some(value))) => mask |= value,
+                        Some((ident, None)) => deferred.push(quote! { (#name::#ident as usize) }),
+                        None => {
+                            return syn::Error::new(
+                                member.span(),
+                                format!(
+                                    "[`{bundle}`]: `{member}` is not a variant of the `{enum_name}` enum"
+                                ),
+                            )
+                            .to_compile_error()
+                            .into();
+                        }
+                    }
+                }
+
+                let bundle_str = bundle.to_string();
+                let constructor = to_snake_case(bundle);
+
+                if RESERVED_BUNDLE_NAMES.contains(&constructor.to_string().as_str()) {
+                    return syn::Error::new(
+                        bundle.span(),
+                        format!(
+                            "[`{bundle}`]: `{constructor}` is reserved and cannot be used as a bundle name"
+                        ),
+                    )
+                    .to_compile_error()
+                    .into();
                 }
+
+                if let Some((_, other)) = constructor_names
+                    .iter()
+                    .find(|(name, _)| *name == constructor)
+                {
+                    return syn::Error::new(
+                        bundle.span(),
+                        format!(
+                            "[`{bundle}`]: `{constructor}` is already the constructor of the `{other}` bundle"
+                        ),
+                    )
+                    .to_compile_error()
+                    .into();
+                }
+
+                constructor_names.push((constructor.clone(), bundle));
+
+                bundle_constructors.push(quote! {
+                    #[doc = concat!("Returns a new manager with the roles of the `", #bundle_str, "` bundle.")]
+                    pub fn #constructor() -> bit_roles::RoleManager<#name> {
+                        bit_roles::RoleManager(#mask #(| #deferred)*, PhantomData)
+                    }
+                });
             }
 
+            // Mask of every declared role. Variants with non-literal discriminants are
+            // combined into the mask at compile-time.
+            let declared_mask = variants
+                .iter()
+                .fold(0, |mask, (_, value)| mask | value.unwrap_or(0));
+
+            let deferred_idents = variants
+                .iter()
+                .filter(|(_, value)| value.is_none())
+                .map(|(ident, _)| ident)
+                .collect::<Vec<_>>();
+
+            // Ensure the non-literal discriminants are zero or a power of two.
+            let deferred_assertions = deferred_idents.iter().map(|ident| {
+                let message = format!(
+                    "[`{ident}`]: the discriminant of `{ident}` in the `{enum_name}` enum is neither zero nor a power of two"
+                );
+
+                quote! {
+                    const _: () = assert!(
+                        {
+                            let value = #name::#ident as usize;
+                            value == 0 || value.is_power_of_two()
+                        },
+                        #message
+                    );
+                }
+            });
+
+            // Mask constants for each of the variant groups.
+            let group_masks = groups.iter().map(|(group, mask, deferred)| {
+                let ident = format_ident!("{}_MASK", group.to_uppercase());
+                quote! {
+                    #[doc = concat!("The mask of the roles in the `", #group, "` group.")]
+                    pub const #ident: usize = #mask #(| #deferred)*;
+                }
+            });
+
+            // Declared roles, excluding the zero variant.
+            let declared_roles = variants.iter().filter_map(|(ident, value)| match value {
+                Some(0) => None,
+                Some(_) => Some(quote! { Some(#name::#ident) }),
+                None => Some(quote! { (#name::#ident as usize != 0).then_some(#name::#ident) }),
+            });
+
+            // Match arms for the variant names. Skipped variants keep their names
+            // but cannot be resolved from them.
+            let name_arms = variants
+                .iter()
+                .map(|(ident, _)| ident)
+                .chain(&skipped)
+                .map(|ident| {
+                    let ident_str = ident.to_string();
+                    quote! { #name::#ident => #ident_str, }
+                });
+
+            let from_name_arms = variants.iter().map(|(ident, _)| {
+                let ident_str = ident.to_string();
+                quote! { #ident_str => Some(#name::#ident), }
+            });
+
+            // Match arms for decoding a value back into its variant.
+            let try_from_arms = variants.iter().map(|(ident, value)| match value {
+                Some(value) => quote! { #value => Ok(#name::#ident), },
+                None => quote! { value if value == #name::#ident as usize => Ok(#name::#ident), },
+            });
+
+            // Skipped variants convert to the empty role so that they never
+            // contribute bits to a manager.
+            let into_body = if skipped.is_empty() {
+                quote! { self as usize }
+            } else {
+                quote! {
+                    match self {
+                        #(#name::#skipped)|* => 0,
+                        _ => self as usize,
+                    }
+                }
+            };
+
             let expanded = quote! {
                 use bit_roles::BitRoleImpl;
                 use std::marker::PhantomData;
 
+                #(#deferred_assertions)*
+
+                #(#zero_assertions)*
+
+                // Ensure the role enum derives `Copy`.
+                const _: fn() = || {
+                    fn assert_copy<T: Copy>() {}
+                    assert_copy::<#name>();
+                };
+
                 impl #impl_generics Into<usize> for #name #ty_generics #where_clause {
                     fn into(self) -> usize {
-                        self as usize
+                        #into_body
                     }
                 }
 
                 impl #impl_generics bit_roles::RoleVariant for #name #ty_generics #where_clause {}
 
+                impl #impl_generics #name #ty_generics #where_clause {
+                    #(#group_masks)*
+
+                    #(#bundle_constructors)*
+
+                    /// Returns the description of the role variant, or its name if no
+                    /// description was provided.
+                    pub fn describe(self) -> &'static str {
+                        match self {
+                            #(#describe_arms)*
+                        }
+                    }
+                }
+
+                impl #impl_generics TryFrom<usize> for #name #ty_generics #where_clause {
+                    type Error = bit_roles::RoleError;
+
+                    fn try_from(value: usize) -> Result<Self, Self::Error> {
+                        match value {
+                            #(#try_from_arms)*
+                            _ => Err(bit_roles::RoleError::InvalidRole(value)),
+                        }
+                    }
+                }
+
                 impl #impl_generics BitRoleImpl<#name> for #name #ty_generics #where_clause {
+                    const DECLARED_MASK: usize = #declared_mask #(| (#name::#deferred_idents as usize))*;
+
                     fn empty() -> bit_roles::RoleManager<#name> {
                         bit_roles::RoleManager(0, PhantomData)
                     }
 
-                    fn from_value(value: usize) -> bit_roles::RoleManager<#name> {
-                        bit_roles::RoleManager(value, PhantomData)
+                    fn all_roles() -> Vec<#name> {
+                        [#(#declared_roles),*].into_iter().flatten().collect()
+                    }
+
+                    fn name(self) -> &'static str {
+                        match self {
+                            #(#name_arms)*
+                        }
+                    }
+
+                    fn from_name(name: &str) -> Option<#name> {
+                        match name {
+                            #(#from_name_arms)*
+                            _ => None,
+                        }
                     }
                 }
             };
@@ -1,4 +1,13 @@
-use bit_roles::BitRole;
+use bit_roles::{
+    all_of,
+    any_of,
+    none_of,
+    BitRole,
+    RoleError,
+    RoleManager,
+    RoleValue,
+    RoleVariant,
+};
 use std::ops::BitOrAssign;
 
 #[allow(dead_code)]
@@ -9,6 +18,23 @@ enum TestRole {
     Two = 2,
 }
 
+// Kept in a module, as the derive imports its helpers into the scope of the
+// enum and would collide with the ones imported for `TestRole`.
+mod fixtures {
+    use bit_roles::BitRole;
+
+    #[allow(dead_code)]
+    #[derive(Debug, BitRole, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+    pub enum WideTestRole {
+        None = 0,
+        One = 1,
+        Two = 2,
+        Four = 4,
+    }
+}
+
+use fixtures::WideTestRole;
+
 #[test]
 fn can_create_an_empty_manager() {
     let manager = TestRole::empty();
@@ -16,6 +42,7 @@ fn can_create_an_empty_manager() {
 }
 
 #[test]
+#[cfg(not(feature = "strict"))]
 fn can_create_a_manager_from_value() {
     let manager = TestRole::from_value(5);
     assert_eq!(manager.get_value(), 5);
@@ -69,6 +96,35 @@ fn remove_all() {
     assert_eq!(manager.get_value(), 0);
 }
 
+#[test]
+fn add_iter() {
+    let source = [TestRole::None, TestRole::One, TestRole::Two];
+    let mut manager = TestRole::empty();
+    manager.add_iter(
+        source
+            .iter()
+            .copied()
+            .filter(|role| !matches!(role, TestRole::Two)),
+    );
+
+    assert_eq!(manager.get_value(), TestRole::One as usize);
+}
+
+#[test]
+fn remove_iter() {
+    let source = [TestRole::None, TestRole::One, TestRole::Two];
+    let mut manager = TestRole::empty();
+    manager.add_all(vec![TestRole::One, TestRole::Two]);
+    manager.remove_iter(
+        source
+            .iter()
+            .copied()
+            .filter(|role| !matches!(role, TestRole::Two)),
+    );
+
+    assert_eq!(manager.get_value(), TestRole::Two as usize);
+}
+
 #[test]
 fn has_one() {
     let mut manager = TestRole::empty();
@@ -137,6 +193,18 @@ fn not_any() {
     assert!(!manager.not_any(vec![TestRole::One, TestRole::Two]));
 }
 
+#[test]
+fn has_only() {
+    let mut manager = TestRole::empty();
+    manager.add_one(TestRole::One);
+
+    assert!(manager.has_only(&[TestRole::One, TestRole::Two]));
+
+    manager.add_one(TestRole::Two);
+
+    assert!(!manager.has_only(&[TestRole::One]));
+}
+
 #[test]
 fn equality() {
     let mut m1 = TestRole::empty();
@@ -147,3 +215,1077 @@ fn equality() {
 
     assert_eq!(m1, m2);
 }
+
+#[test]
+fn diff_value() {
+    let m1 = RoleManager::<TestRole>::from_value_unchecked(0b0101);
+    let m2 = RoleManager::<TestRole>::from_value_unchecked(0b0110);
+
+    assert_eq!(m1.diff_value(&m2), 0b0011);
+    assert_eq!(m1.diff_value(&m1), 0);
+}
+
+#[test]
+fn try_from_usize() {
+    assert!(matches!(TestRole::try_from(2), Ok(TestRole::Two)));
+    assert!(matches!(
+        TestRole::try_from(4),
+        Err(RoleError::InvalidRole(4))
+    ));
+}
+
+#[test]
+fn satisfies() {
+    let mut manager = TestRole::empty();
+    manager.add_one(TestRole::One);
+
+    let guard = |manager: &RoleManager<TestRole>| {
+        all_of(&[TestRole::One])(manager) && none_of(&[TestRole::Two])(manager)
+    };
+
+    assert!(manager.satisfies(guard));
+    assert!(manager.satisfies(any_of(&[TestRole::One, TestRole::Two])));
+
+    manager.add_one(TestRole::Two);
+
+    assert!(!manager.satisfies(guard));
+}
+
+#[test]
+fn is_superset_of_and_is_subset_of() {
+    let mut m1 = TestRole::empty();
+    let mut m2 = TestRole::empty();
+
+    m1.add_one(TestRole::One);
+    m2.add_one(TestRole::One);
+
+    // Equal managers
+    assert!(m1.is_superset_of(&m2));
+    assert!(m1.is_subset_of(&m2));
+
+    // Strict subset
+    m2.add_one(TestRole::Two);
+
+    assert!(m1.is_subset_of(&m2));
+    assert!(!m1.is_superset_of(&m2));
+    assert!(m2.is_superset_of(&m1));
+    assert!(!m2.is_subset_of(&m1));
+
+    // Disjoint managers
+    m2.remove_one(TestRole::One);
+
+    assert!(!m1.is_subset_of(&m2));
+    assert!(!m1.is_superset_of(&m2));
+}
+
+#[test]
+fn from_roles() {
+    let manager = RoleManager::from_roles(&[TestRole::One, TestRole::Two]);
+
+    let mut expected = TestRole::empty();
+    expected.add_one(TestRole::One);
+    expected.add_one(TestRole::Two);
+
+    assert_eq!(manager, expected);
+}
+
+#[test]
+fn not() {
+    let manager = TestRole::from_value(TestRole::One as usize);
+
+    assert_eq!(!manager, TestRole::from_value(TestRole::Two as usize));
+    assert_eq!(!TestRole::empty(), TestRole::all());
+}
+
+#[test]
+fn remove_value() {
+    let mut manager = RoleManager::<TestRole>::from_value_unchecked(0b0111);
+    manager.remove_value(0b0100);
+
+    assert_eq!(manager.get_value(), 0b0011);
+
+    manager.remove_value(TestRole::One as usize);
+
+    assert_eq!(manager.get_value(), TestRole::Two as usize);
+}
+
+#[test]
+fn has_value() {
+    let manager = RoleManager::<TestRole>::from_value_unchecked(0b0101);
+
+    assert!(manager.has_value(0b0100));
+    assert!(manager.has_value(TestRole::One as usize));
+    assert!(!manager.has_value(TestRole::Two as usize));
+}
+
+#[test]
+fn apply_diff() {
+    let mut m1 = TestRole::from_value(TestRole::One as usize);
+    let m2 = TestRole::from_value(TestRole::Two as usize);
+    let diff = m1.diff_value(&m2);
+
+    m1.apply_diff(diff);
+
+    assert_eq!(m1, m2);
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn from_value_keeps_undeclared_roles() {
+    let manager = TestRole::from_value(0b1001);
+
+    assert_eq!(manager.get_value(), 0b1001);
+}
+
+#[test]
+fn bits() {
+    let manager = RoleManager::<TestRole>::from_value_unchecked(0b1010);
+
+    assert_eq!(manager.bits().collect::<Vec<_>>(), vec![1, 3]);
+    assert_eq!(TestRole::empty().bits().count(), 0);
+}
+
+#[test]
+fn is_valid() {
+    let mut manager = TestRole::from_value(TestRole::One as usize);
+
+    assert!(manager.is_valid());
+
+    manager.0 |= 0b1000;
+
+    assert!(!manager.is_valid());
+}
+
+#[test]
+fn retain_value() {
+    let mut manager = RoleManager::<TestRole>::from_value_unchecked(0b0111);
+    manager.retain_value(0b0110);
+
+    assert_eq!(manager.get_value(), 0b0110);
+
+    manager.remove_value(0b0110);
+
+    assert_eq!(manager.get_value(), 0);
+}
+
+#[test]
+fn count_missing() {
+    let manager = WideTestRole::from_value(WideTestRole::Two as usize);
+
+    assert_eq!(
+        manager.count_missing(&[WideTestRole::One, WideTestRole::Two, WideTestRole::Four]),
+        2
+    );
+}
+
+#[test]
+fn from_value_unchecked() {
+    let manager = RoleManager::<TestRole>::from_value_unchecked(0b1001);
+
+    assert_eq!(manager.get_value(), 0b1001);
+}
+
+#[test]
+fn combine() {
+    let manager = RoleManager::combine(&[
+        TestRole::from_value(TestRole::None as usize),
+        TestRole::from_value(TestRole::One as usize),
+        TestRole::from_value(TestRole::Two as usize),
+    ]);
+
+    assert!(manager.has_all(vec![TestRole::One, TestRole::Two]));
+    assert_eq!(RoleManager::<TestRole>::combine(&[]).get_value(), 0);
+}
+
+#[test]
+fn flip() {
+    let mut manager = TestRole::from_value(TestRole::One as usize);
+    manager.flip();
+
+    assert_eq!(manager.get_value(), TestRole::Two as usize);
+
+    manager.flip();
+
+    assert_eq!(manager.get_value(), TestRole::One as usize);
+}
+
+#[test]
+fn from_names() {
+    let manager = TestRole::from_names(&["One", "Two"]).unwrap();
+
+    assert!(manager.has_all(vec![TestRole::One, TestRole::Two]));
+
+    let names: &[&str] = &["One", "Four", "Two", "Eight"];
+    let result = RoleManager::<TestRole>::try_from(names);
+
+    match result {
+        Err(RoleError::UnknownNames(unknown)) => {
+            assert_eq!(unknown, vec!["Four".to_string(), "Eight".to_string()]);
+        }
+        _ => panic!("expected unknown names"),
+    }
+}
+
+#[test]
+fn try_as_u32() {
+    let manager = TestRole::from_value(TestRole::Two as usize);
+
+    assert_eq!(manager.try_as_u32().unwrap(), 2);
+
+    #[cfg(target_pointer_width = "64")]
+    {
+        let manager = RoleManager::<TestRole>::from_value_unchecked(1 << 32);
+
+        assert!(matches!(
+            manager.try_as_u32(),
+            Err(RoleError::Overflow(value)) if value == 1 << 32
+        ));
+    }
+}
+
+#[test]
+fn try_as_u64() {
+    let manager = TestRole::from_value(TestRole::Two as usize);
+
+    assert_eq!(manager.try_as_u64().unwrap(), 2);
+}
+
+#[test]
+fn report() {
+    let mut manager = WideTestRole::empty();
+    manager.add_all(vec![WideTestRole::One, WideTestRole::Four]);
+
+    let report = manager
+        .report()
+        .into_iter()
+        .map(|(role, set)| (role as usize, set))
+        .collect::<Vec<_>>();
+
+    assert_eq!(report, vec![(1, true), (2, false), (4, true)]);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "manager contains undeclared roles")]
+fn add_one_asserts_declared_roles() {
+    let mut manager = TestRole::empty();
+    manager.0 |= 0b1000;
+    manager.add_one(TestRole::One);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "manager contains undeclared roles")]
+fn remove_iter_asserts_declared_roles() {
+    let mut manager = TestRole::empty();
+    manager.0 |= 0b1000;
+    manager.remove_iter([TestRole::One]);
+}
+
+#[test]
+fn assign() {
+    let mut source = TestRole::empty();
+    source.add_all(vec![TestRole::One, TestRole::Two]);
+
+    let mut manager = TestRole::empty();
+    manager.assign(&source);
+
+    assert_eq!(manager, source);
+}
+
+#[test]
+fn diff_roles() {
+    let previous =
+        WideTestRole::from_value(WideTestRole::One as usize | WideTestRole::Two as usize);
+    let current =
+        WideTestRole::from_value(WideTestRole::Two as usize | WideTestRole::Four as usize);
+
+    let diff = previous.diff_roles(&current);
+
+    assert!(matches!(diff.added.as_slice(), [WideTestRole::Four]));
+    assert!(matches!(diff.removed.as_slice(), [WideTestRole::One]));
+}
+
+#[test]
+fn toggle_value() {
+    let mut manager = TestRole::from_value(TestRole::One as usize);
+    manager.toggle_value(0b0011);
+
+    assert_eq!(manager.get_value(), TestRole::Two as usize);
+
+    manager.toggle_value(0b0011);
+
+    assert_eq!(manager.get_value(), TestRole::One as usize);
+}
+
+#[test]
+fn roles_where() {
+    let manager =
+        WideTestRole::from_value(WideTestRole::One as usize | WideTestRole::Four as usize);
+    let roles = manager.roles_where(|role| role as usize > 1);
+
+    assert!(matches!(roles.as_slice(), [WideTestRole::Four]));
+}
+
+#[test]
+fn has_value_of() {
+    let manager = TestRole::from_value(TestRole::One as usize);
+
+    assert!(manager.has_value_of(RoleValue::Role(TestRole::One)));
+    assert!(manager.has_value_of(RoleValue::Raw(1)));
+    assert!(!manager.has_value_of(RoleValue::Role(TestRole::Two)));
+    assert!(!manager.has_value_of(RoleValue::Raw(2)));
+}
+
+#[test]
+fn highest_and_lowest() {
+    #[allow(dead_code)]
+    #[derive(Debug, BitRole, Copy, Clone)]
+    enum Role {
+        None = 0,
+        One = 1,
+        Two = 2,
+        Four = 4,
+        Eight = 8,
+    }
+
+    let manager = Role::from_value(Role::Two as usize | Role::Eight as usize);
+
+    assert!(matches!(manager.highest(), Some(Role::Eight)));
+    assert!(matches!(manager.lowest(), Some(Role::Two)));
+    assert!(Role::empty().highest().is_none());
+    assert!(Role::empty().lowest().is_none());
+}
+
+#[test]
+fn with_value() {
+    let manager = TestRole::empty().with_value(4);
+
+    assert_eq!(manager.get_value(), 4);
+}
+
+#[test]
+fn equality_with_a_single_role() {
+    let mut manager = TestRole::empty();
+    manager.add_one(TestRole::One);
+
+    assert!(manager == TestRole::One);
+    assert!(manager != TestRole::Two);
+
+    manager.add_one(TestRole::Two);
+
+    assert!(manager != TestRole::One);
+    assert!(manager.has_one(TestRole::One));
+    assert!(TestRole::empty() == TestRole::None);
+}
+
+#[test]
+fn intersect_with() {
+    let mut manager =
+        RoleManager::from_roles(&[WideTestRole::One, WideTestRole::Two, WideTestRole::Four]);
+    manager.intersect_with(&RoleManager::from_roles(&[
+        WideTestRole::Two,
+        WideTestRole::Four,
+    ]));
+
+    assert_eq!(
+        manager.get_value(),
+        WideTestRole::Two as usize | WideTestRole::Four as usize
+    );
+}
+
+#[test]
+fn union_with() {
+    let mut manager = TestRole::from_value(TestRole::One as usize);
+    manager.union_with(&TestRole::from_value(TestRole::Two as usize));
+
+    assert_eq!(manager.get_value(), 3);
+}
+
+#[test]
+fn subtract() {
+    let mut manager = TestRole::from_value(3);
+    manager.subtract(&TestRole::from_value(TestRole::Two as usize));
+
+    assert_eq!(manager.get_value(), TestRole::One as usize);
+}
+
+#[test]
+fn le_bytes_round_trip() {
+    let manager = TestRole::from_value(3);
+    let bytes = manager.to_le_bytes();
+
+    assert_eq!(bytes, [3, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(RoleManager::<TestRole>::from_le_bytes(bytes), manager);
+    assert_eq!(
+        RoleManager::<TestRole>::from_le_bytes([0; 8]),
+        TestRole::empty()
+    );
+}
+
+#[test]
+fn le_bytes_round_trip_keeps_undeclared_roles() {
+    let manager = RoleManager::<TestRole>::from_value_unchecked(0b0101);
+
+    assert_eq!(
+        RoleManager::<TestRole>::from_le_bytes(manager.to_le_bytes()),
+        manager
+    );
+}
+
+#[test]
+#[cfg(target_pointer_width = "32")]
+#[should_panic(expected = "`bytes` hold a value beyond the width of `usize`")]
+fn from_le_bytes_overflow() {
+    let _ = RoleManager::<TestRole>::from_le_bytes((1u64 << 40).to_le_bytes());
+}
+
+#[test]
+#[cfg(not(feature = "strict"))]
+fn clear_invalid() {
+    let mut manager = TestRole::from_value(TestRole::One as usize | 4 | 16);
+
+    assert_eq!(manager.clear_invalid(), 2);
+    assert_eq!(manager.get_value(), TestRole::One as usize);
+    assert_eq!(manager.clear_invalid(), 0);
+}
+
+#[test]
+fn iter_named() {
+    let manager = TestRole::from_value(3);
+    let pairs = manager.iter_named().collect::<Vec<_>>();
+
+    assert!(matches!(
+        pairs.as_slice(),
+        [(TestRole::One, "One"), (TestRole::Two, "Two")]
+    ));
+    assert_eq!(TestRole::empty().iter_named().count(), 0);
+}
+
+#[test]
+fn try_fold_roles() {
+    let manager =
+        WideTestRole::from_value(WideTestRole::One as usize | WideTestRole::Four as usize);
+    let sum = manager.try_fold_roles(0, |sum, role| Ok::<_, ()>(sum + role as usize));
+
+    assert_eq!(sum, Ok(5));
+
+    let manager = WideTestRole::from_value(7);
+    let result = manager.try_fold_roles(0, |sum, role| {
+        let sum = sum + role as usize;
+
+        if sum > 2 {
+            Err(sum)
+        } else {
+            Ok(sum)
+        }
+    });
+
+    assert_eq!(result, Err(3));
+}
+
+#[test]
+fn as_set() {
+    let manager = WideTestRole::from_value(WideTestRole::Four as usize | WideTestRole::One as usize);
+    let set = manager.as_set();
+
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&WideTestRole::One));
+    assert!(set.contains(&WideTestRole::Four));
+    assert!(!set.contains(&WideTestRole::Two));
+    assert_eq!(
+        set.into_iter().collect::<Vec<_>>(),
+        vec![WideTestRole::One, WideTestRole::Four]
+    );
+}
+
+#[test]
+fn require_all() {
+    let manager = TestRole::from_value(TestRole::One as usize);
+
+    assert!(manager.require_all(&[TestRole::One]).is_ok());
+    assert!(manager.require_all(&[]).is_ok());
+
+    let result = manager.require_all(&[TestRole::One, TestRole::Two]);
+
+    assert!(matches!(
+        result,
+        Err(RoleError::Missing(missing)) if missing == vec![TestRole::Two as usize]
+    ));
+}
+
+#[test]
+fn require_any() {
+    let manager = TestRole::from_value(TestRole::Two as usize);
+
+    assert!(manager.require_any(&[TestRole::One, TestRole::Two]).is_ok());
+    assert!(matches!(
+        TestRole::empty().require_any(&[TestRole::One, TestRole::Two]),
+        Err(RoleError::Missing(missing)) if missing == vec![1, 2]
+    ));
+    assert!(manager.require_any(&[]).is_err());
+}
+
+#[test]
+fn first_missing() {
+    let manager = WideTestRole::from_value(WideTestRole::One as usize);
+
+    assert!(matches!(
+        manager.first_missing(&[WideTestRole::One, WideTestRole::Two, WideTestRole::Four]),
+        Some(WideTestRole::Two)
+    ));
+    assert!(manager.first_missing(&[WideTestRole::One]).is_none());
+}
+
+#[test]
+fn as_ref_and_borrow() {
+    use std::borrow::Borrow;
+
+    fn raw_value(value: impl AsRef<usize>) -> usize {
+        *value.as_ref()
+    }
+
+    let manager = TestRole::from_value(TestRole::Two as usize);
+
+    assert_eq!(raw_value(&manager), TestRole::Two as usize);
+    assert_eq!(*Borrow::<usize>::borrow(&manager), TestRole::Two as usize);
+}
+
+#[test]
+fn invert_except() {
+    let mut manager =
+        WideTestRole::from_value(WideTestRole::One as usize | WideTestRole::Two as usize);
+    manager.invert_except(&[WideTestRole::One]);
+
+    assert_eq!(
+        manager.get_value(),
+        WideTestRole::One as usize | WideTestRole::Four as usize
+    );
+
+    manager.invert_except(&[WideTestRole::Four]);
+
+    assert_eq!(
+        manager.get_value(),
+        WideTestRole::Two as usize | WideTestRole::Four as usize
+    );
+}
+
+#[test]
+#[cfg(not(feature = "strict"))]
+fn diagnostics() {
+    let manager = TestRole::from_value(TestRole::One as usize | 1 << 3 | 1 << 7);
+
+    assert_eq!(manager.diagnostics(), vec![3, 7]);
+    assert!(TestRole::from_value(3).diagnostics().is_empty());
+}
+
+#[test]
+fn count_in() {
+    #[allow(dead_code)]
+    #[derive(Debug, BitRole, Copy, Clone)]
+    enum Role {
+        None = 0,
+        #[bit_role(group = "content")]
+        One = 1,
+        #[bit_role(group = "content")]
+        Two = 2,
+        Four = 4,
+    }
+
+    let manager = Role::from_value(7);
+
+    assert_eq!(manager.count_in(Role::CONTENT_MASK), 2);
+    assert_eq!(manager.count_in(Role::Four as usize), 1);
+    assert_eq!(Role::empty().count_in(Role::CONTENT_MASK), 0);
+}
+
+#[test]
+fn try_revoke() {
+    let mut manager = TestRole::from_value(TestRole::One as usize);
+
+    assert!(matches!(
+        manager.try_revoke(TestRole::Two),
+        Err(RoleError::NotHeld(2))
+    ));
+    assert_eq!(manager.get_value(), TestRole::One as usize);
+    assert!(manager.try_revoke(TestRole::One).is_ok());
+    assert_eq!(manager.get_value(), 0);
+}
+
+#[test]
+fn snapshot_and_restore() {
+    let mut manager = TestRole::from_value(TestRole::One as usize);
+    let snapshot = manager.snapshot();
+
+    manager.add_one(TestRole::Two).remove_one(TestRole::One);
+
+    assert_eq!(manager.get_value(), TestRole::Two as usize);
+
+    manager.restore(snapshot);
+
+    assert_eq!(manager.get_value(), TestRole::One as usize);
+}
+
+#[test]
+fn merge_iter() {
+    let mut manager = WideTestRole::empty();
+    manager.merge_iter(
+        [WideTestRole::One, WideTestRole::Two, WideTestRole::Four]
+            .into_iter()
+            .map(|role| WideTestRole::from_value(role as usize)),
+    );
+
+    assert_eq!(manager.get_value(), 7);
+}
+
+#[test]
+fn has_all_value_and_has_any_value() {
+    #[allow(dead_code)]
+    #[derive(Debug, BitRole, Copy, Clone)]
+    enum Role {
+        None = 0,
+        One = 1,
+        Two = 2,
+        Four = 4,
+        Eight = 8,
+    }
+
+    let manager = Role::from_value(Role::One as usize | Role::Two as usize);
+
+    // Fully contained.
+    assert!(manager.has_all_value(3));
+    assert!(manager.has_any_value(3));
+
+    // Partially contained.
+    assert!(!manager.has_all_value(6));
+    assert!(manager.has_any_value(6));
+
+    // Disjoint.
+    assert!(!manager.has_all_value(12));
+    assert!(!manager.has_any_value(12));
+}
+
+#[test]
+fn into_unchecked() {
+    let manager = TestRole::from_value(3).into_unchecked();
+
+    assert_eq!(manager.get_value(), 3);
+    assert!(manager.has_all(vec![TestRole::One, TestRole::Two]));
+}
+
+#[test]
+fn grant_mask_and_revoke_mask() {
+    #[allow(dead_code)]
+    #[derive(Debug, BitRole, Copy, Clone)]
+    enum Role {
+        None = 0,
+        One = 1,
+        #[bit_role(group = "content")]
+        Two = 2,
+        #[bit_role(group = "content")]
+        Four = 4,
+    }
+
+    let mut manager = Role::from_value(Role::One as usize);
+    manager.grant_mask(Role::CONTENT_MASK);
+
+    assert_eq!(manager.get_value(), 7);
+
+    manager.revoke_mask(Role::CONTENT_MASK);
+
+    assert_eq!(manager.get_value(), Role::One as usize);
+}
+
+#[test]
+fn intersection_all() {
+    let manager = RoleManager::intersection_all(&[
+        WideTestRole::from_value(WideTestRole::One as usize | WideTestRole::Two as usize),
+        WideTestRole::from_value(WideTestRole::Two as usize | WideTestRole::Four as usize),
+        WideTestRole::from_value(7),
+    ]);
+
+    assert_eq!(manager.get_value(), WideTestRole::Two as usize);
+    assert_eq!(
+        RoleManager::<WideTestRole>::intersection_all(&[]).get_value(),
+        0
+    );
+}
+
+#[test]
+fn matrix() {
+    let manager =
+        WideTestRole::from_value(WideTestRole::One as usize | WideTestRole::Four as usize);
+
+    assert_eq!(WideTestRole::DECLARED_BITS, 3);
+    assert_eq!(manager.matrix(), vec![true, false, true]);
+    assert_eq!(WideTestRole::empty().matrix(), vec![false; 3]);
+}
+
+#[test]
+fn is_value() {
+    let manager = TestRole::from_value(TestRole::Two as usize);
+
+    assert!(manager.is_value(2));
+    assert!(!manager.is_value(3));
+    assert!(!manager.is_value(0));
+}
+
+#[test]
+fn iter() {
+    #[allow(dead_code)]
+    #[derive(Debug, BitRole, Copy, Clone)]
+    enum Role {
+        None = 0,
+        Four = 4,
+        One = 1,
+        Two = 2,
+    }
+
+    let manager = Role::from_value(7);
+
+    assert!(matches!(
+        manager.iter().collect::<Vec<_>>().as_slice(),
+        [Role::One, Role::Two, Role::Four]
+    ));
+    assert!(matches!(
+        manager.iter().rev().collect::<Vec<_>>().as_slice(),
+        [Role::Four, Role::Two, Role::One]
+    ));
+    assert_eq!(Role::empty().iter().len(), 0);
+}
+
+#[test]
+#[cfg(not(feature = "strict"))]
+fn count_range() {
+    let manager = TestRole::from_value(0b0000_0101_0000_0011);
+
+    assert_eq!(manager.count_range(0, 8), 2);
+    assert_eq!(manager.count_range(8, 16), 2);
+    assert_eq!(manager.count_range(1, 9), 2);
+    assert_eq!(manager.count_range(4, 4), 0);
+    assert_eq!(manager.count_range(8, 4), 0);
+    assert_eq!(manager.count_range(0, usize::BITS + 1), 4);
+}
+
+#[test]
+fn to_names() {
+    let manager = TestRole::from_value(3);
+
+    assert_eq!(manager.to_names(", "), "One, Two");
+    assert_eq!(TestRole::empty().to_names(", "), "");
+}
+
+#[test]
+fn parse_names_lossy() {
+    let (manager, unknown) =
+        RoleManager::<TestRole>::parse_names_lossy("One | Four | Two | Eight", "|");
+
+    assert_eq!(manager.get_value(), 3);
+    assert_eq!(unknown, vec!["Four".to_string(), "Eight".to_string()]);
+
+    let (manager, unknown) = RoleManager::<TestRole>::parse_names_lossy("", ",");
+
+    assert_eq!(manager.get_value(), 0);
+    assert!(unknown.is_empty());
+}
+
+#[test]
+fn remap() {
+    let manager =
+        WideTestRole::from_value(WideTestRole::One as usize | WideTestRole::Four as usize);
+
+    // Swap the first two bits.
+    let swapped = manager.remap(|position| match position {
+        0 => 1,
+        1 => 0,
+        other => other,
+    });
+
+    assert_eq!(
+        swapped.get_value(),
+        WideTestRole::Two as usize | WideTestRole::Four as usize
+    );
+
+    // Colliding bits are combined.
+    let collapsed = WideTestRole::from_value(7).remap(|_| 0);
+
+    assert_eq!(collapsed.get_value(), WideTestRole::One as usize);
+}
+
+/// A role with a manual, invalid implementation of [RoleVariant].
+#[derive(Debug, Copy, Clone)]
+struct ManualRole;
+
+impl From<ManualRole> for usize {
+    fn from(_: ManualRole) -> Self {
+        3
+    }
+}
+
+impl RoleVariant for ManualRole {}
+
+impl bit_roles::BitRoleImpl<ManualRole> for ManualRole {
+    const DECLARED_MASK: usize = 3;
+
+    fn empty() -> RoleManager<ManualRole> {
+        RoleManager(0, std::marker::PhantomData)
+    }
+
+    fn all_roles() -> Vec<ManualRole> {
+        vec![ManualRole]
+    }
+
+    fn name(self) -> &'static str {
+        "ManualRole"
+    }
+
+    fn from_name(_: &str) -> Option<ManualRole> {
+        None
+    }
+}
+
+#[test]
+fn is_valid_role_variant() {
+    assert!(TestRole::One.is_valid());
+    assert!(TestRole::None.is_valid());
+    assert!(!ManualRole.is_valid());
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "`role` holds an invalid value")]
+fn add_one_asserts_valid_role_variants() {
+    ManualRole::empty().add_one(ManualRole);
+}
+
+#[test]
+fn try_grant_values() {
+    let mut manager = TestRole::empty();
+
+    assert!(
+        manager
+            .try_grant_values(&[TestRole::Two as usize, 0])
+            .is_ok()
+    );
+    assert_eq!(manager.get_value(), TestRole::Two as usize);
+
+    let result = manager.try_grant_values(&[TestRole::One as usize, 8]);
+
+    assert!(matches!(result, Err(RoleError::Undeclared(8))));
+    assert_eq!(manager.get_value(), TestRole::Two as usize);
+
+    let result = manager.try_grant_values(&[3]);
+
+    assert!(matches!(result, Err(RoleError::InvalidRole(3))));
+}
+
+#[test]
+fn xor_value() {
+    let base = TestRole::from_value(TestRole::One as usize);
+
+    assert_eq!(base.xor_value(3).get_value(), TestRole::Two as usize);
+    assert_eq!(base.xor_value(0).get_value(), TestRole::One as usize);
+    assert_eq!(base.get_value(), TestRole::One as usize);
+}
+
+#[test]
+fn and_not_value() {
+    let base = TestRole::from_value(3);
+
+    assert_eq!(base.and_not_value(1).get_value(), TestRole::Two as usize);
+    assert_eq!(base.and_not_value(3).get_value(), 0);
+    assert_eq!(base.get_value(), 3);
+}
+
+#[test]
+fn bit_for() {
+    assert_eq!(RoleManager::bit_for(WideTestRole::One), 0);
+    assert_eq!(RoleManager::bit_for(WideTestRole::Four), 2);
+    assert_eq!(RoleManager::bit_for(WideTestRole::None), usize::BITS);
+}
+
+#[test]
+fn from_positions() {
+    let manager = RoleManager::<WideTestRole>::from_positions(&[0, 2]);
+
+    assert_eq!(
+        manager.get_value(),
+        WideTestRole::One as usize | WideTestRole::Four as usize
+    );
+    assert_eq!(RoleManager::<WideTestRole>::from_positions(&[]).get_value(), 0);
+}
+
+#[test]
+#[should_panic(expected = "`positions` contain a position beyond the width of `usize`")]
+fn from_positions_rejects_wide_positions() {
+    let _ = RoleManager::<WideTestRole>::from_positions(&[0, usize::BITS]);
+}
+
+#[test]
+fn checked_from_value() {
+    assert_eq!(
+        RoleManager::<TestRole>::checked_from_value(3).map(|manager| manager.get_value()),
+        Some(3)
+    );
+    assert!(RoleManager::<TestRole>::checked_from_value(TestRole::One as usize | 8).is_none());
+}
+
+#[test]
+fn available() {
+    let manager = WideTestRole::from_value(WideTestRole::Two as usize);
+
+    assert!(matches!(
+        manager.available().as_slice(),
+        [WideTestRole::One, WideTestRole::Four]
+    ));
+    assert!(WideTestRole::from_value(7).available().is_empty());
+}
+
+#[test]
+fn jaccard() {
+    let shared = WideTestRole::from_value(WideTestRole::One as usize | WideTestRole::Two as usize)
+        .jaccard(&WideTestRole::from_value(
+            WideTestRole::Two as usize | WideTestRole::Four as usize,
+        ));
+
+    assert_eq!(shared, 1.0 / 3.0);
+
+    let first = TestRole::from_value(3);
+    let second = TestRole::from_value(TestRole::Two as usize);
+
+    assert_eq!(first.jaccard(&second), 0.5);
+    assert_eq!(first.jaccard(&first), 1.0);
+    assert_eq!(TestRole::empty().jaccard(&TestRole::empty()), 1.0);
+    assert_eq!(
+        TestRole::from_value(TestRole::One as usize).jaccard(&second),
+        0.0
+    );
+}
+
+#[test]
+fn require_exactly() {
+    let manager = WideTestRole::from_value(WideTestRole::One as usize | WideTestRole::Two as usize);
+
+    // Exact match.
+    assert!(
+        manager
+            .require_exactly(&[WideTestRole::Two, WideTestRole::One])
+            .is_ok()
+    );
+
+    // The manager holds a superset of the roles.
+    assert!(matches!(
+        manager.require_exactly(&[WideTestRole::One]),
+        Err(RoleError::Mismatch { missing, extra }) if missing.is_empty() && extra == vec![2]
+    ));
+
+    // The manager holds a subset of the roles.
+    assert!(matches!(
+        manager.require_exactly(&[WideTestRole::One, WideTestRole::Two, WideTestRole::Four]),
+        Err(RoleError::Mismatch { missing, extra }) if missing == vec![4] && extra.is_empty()
+    ));
+
+    // Both lists are formatted like the other role errors.
+    let err = WideTestRole::from_value(WideTestRole::One as usize)
+        .require_exactly(&[WideTestRole::Two, WideTestRole::Four])
+        .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "role mismatch: missing roles: `2`, `4`; extra roles: `1`"
+    );
+}
+
+#[test]
+fn over_and_under() {
+    let base = WideTestRole::from_value(WideTestRole::One as usize | WideTestRole::Two as usize);
+    let layer = WideTestRole::from_value(WideTestRole::Two as usize | WideTestRole::Four as usize);
+
+    assert_eq!(layer.over(&base).get_value(), 7);
+    assert_eq!(layer.under(&base).get_value(), WideTestRole::Two as usize);
+    assert_eq!(base.under(&layer).get_value(), WideTestRole::Two as usize);
+}
+
+#[test]
+fn count_ones_and_leading_zeros() {
+    let manager = TestRole::from_value(3);
+
+    assert_eq!(manager.count_ones(), 2);
+    assert_eq!(manager.leading_zeros(), usize::BITS - 2);
+    assert_eq!(TestRole::empty().count_ones(), 0);
+    assert_eq!(TestRole::empty().leading_zeros(), usize::BITS);
+}
+
+#[test]
+fn partition() {
+    let (assigned, available) = WideTestRole::from_value(WideTestRole::Two as usize).partition();
+
+    assert!(matches!(assigned.as_slice(), [WideTestRole::Two]));
+    assert!(matches!(
+        available.as_slice(),
+        [WideTestRole::One, WideTestRole::Four]
+    ));
+}
+
+#[test]
+fn stable_key() {
+    assert_eq!(TestRole::from_value(3).stable_key(), 3);
+    assert_eq!(TestRole::empty().stable_key(), 0);
+}
+
+#[test]
+fn count() {
+    assert_eq!(TestRole::from_value(3).count(), 2);
+    assert_eq!(TestRole::empty().count(), 0);
+}
+
+#[test]
+fn is_empty() {
+    assert!(TestRole::empty().is_empty());
+    assert!(!TestRole::from_value(TestRole::One as usize).is_empty());
+}
+
+#[test]
+fn clear() {
+    let mut manager = TestRole::from_value(3);
+    manager.clear();
+
+    assert_eq!(manager.get_value(), 0);
+}
+
+#[test]
+fn set_one() {
+    let mut manager = TestRole::from_value(TestRole::Two as usize);
+
+    manager.set_one(TestRole::One, true);
+
+    assert_eq!(manager.get_value(), 3);
+
+    manager.set_one(TestRole::One, false);
+
+    assert_eq!(manager.get_value(), TestRole::Two as usize);
+
+    manager.set_one(TestRole::One, false);
+
+    assert_eq!(manager.get_value(), TestRole::Two as usize);
+}
+
+#[test]
+fn set_all() {
+    let mut manager =
+        WideTestRole::from_value(WideTestRole::One as usize | WideTestRole::Two as usize);
+    manager.set_all(&[
+        (WideTestRole::One, false),
+        (WideTestRole::Four, true),
+        (WideTestRole::Two, true),
+    ]);
+
+    assert_eq!(
+        manager.get_value(),
+        WideTestRole::Two as usize | WideTestRole::Four as usize
+    );
+
+    manager.set_all(&[(WideTestRole::Two, true), (WideTestRole::Two, false)]);
+
+    assert_eq!(manager.get_value(), WideTestRole::Four as usize);
+}
@@ -0,0 +1,12 @@
+use bit_roles::BitRole;
+
+// Enum with an alias variant referencing an undeclared bit.
+#[derive(Debug, BitRole, Copy, Clone)]
+enum Role {
+    None = 0,
+    One = 1,
+    Two = 2,
+    OneAndFour = 5,
+}
+
+fn main() {}
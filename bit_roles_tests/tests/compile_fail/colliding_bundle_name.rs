@@ -0,0 +1,11 @@
+use bit_roles::BitRole;
+
+// Enum with bundles sharing the same constructor name.
+#[derive(Debug, BitRole, Copy, Clone)]
+#[bit_role(bundle(HttpAdmin = [One], HTTPAdmin = [One]))]
+enum Role {
+    None = 0,
+    One = 1,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use bit_roles::BitRole;
+
+// Enum with group names that only differ in case.
+#[derive(Debug, BitRole, Copy, Clone)]
+enum Role {
+    None = 0,
+    #[bit_role(group = "content")]
+    One = 1,
+    #[bit_role(group = "Content")]
+    Two = 2,
+}
+
+fn main() {}
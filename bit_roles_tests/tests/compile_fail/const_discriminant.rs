@@ -0,0 +1,13 @@
+use bit_roles::BitRole;
+
+const THREE: isize = 3;
+
+// Enum with a constant discriminant that is not a power of two.
+#[derive(Debug, BitRole, Copy, Clone)]
+enum Role {
+    None = 0,
+    One = 1,
+    Three = THREE,
+}
+
+fn main() {}
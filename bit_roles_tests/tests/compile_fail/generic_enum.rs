@@ -0,0 +1,13 @@
+use bit_roles::BitRole;
+use std::marker::PhantomData;
+
+// Enum with a generic parameter.
+#[derive(Debug, BitRole, Copy, Clone)]
+#[repr(usize)]
+enum Role<T> {
+    None = 0,
+    One = 1,
+    _Phantom(PhantomData<T>) = 2,
+}
+
+fn main() {}
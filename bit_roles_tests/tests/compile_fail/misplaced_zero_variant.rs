@@ -0,0 +1,21 @@
+use bit_roles::BitRole;
+
+const ZERO: isize = 0;
+
+// Enum with a zero variant declared among the roles.
+#[derive(Debug, BitRole, Copy, Clone)]
+#[bit_role(strict_zero)]
+enum RoleOne {
+    One = 1,
+    None = 0,
+}
+
+// Enum with a constant zero variant declared among the roles.
+#[derive(Debug, BitRole, Copy, Clone)]
+#[bit_role(strict_zero)]
+enum RoleTwo {
+    One = 1,
+    None = ZERO,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use bit_roles::BitRole;
+
+// Enum that does not derive `Copy`.
+#[derive(Debug, BitRole, Clone)]
+enum Role {
+    None = 0,
+    One = 1,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use bit_roles::BitRole;
+
+// Enum with multiple zero variants.
+#[derive(Debug, BitRole, Copy, Clone)]
+enum Role {
+    None = 0,
+    Empty = 0,
+    One = 1,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use bit_roles::BitRole;
+
+// Enum with a discriminant wider than its representation.
+#[derive(Debug, BitRole, Copy, Clone)]
+#[repr(u8)]
+enum Role {
+    None = 0,
+    One = 256,
+}
+
+fn main() {}
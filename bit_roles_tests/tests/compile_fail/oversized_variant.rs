@@ -0,0 +1,14 @@
+use bit_roles::BitRole;
+
+// Enum with a role beyond the width of `usize`. Declaring more roles than the
+// width of `usize` is impossible by construction, as distinct roles occupy
+// distinct bits, so the only way to exceed the width is a single oversized role.
+#[derive(Debug, BitRole, Copy, Clone)]
+#[repr(u128)]
+enum Role {
+    None = 0,
+    One = 1,
+    Overflow = 18446744073709551616,
+}
+
+fn main() {}
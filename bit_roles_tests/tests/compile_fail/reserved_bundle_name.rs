@@ -0,0 +1,11 @@
+use bit_roles::BitRole;
+
+// Enum with a bundle shadowing a generated function.
+#[derive(Debug, BitRole, Copy, Clone)]
+#[bit_role(bundle(All = [One]))]
+enum Role {
+    None = 0,
+    One = 1,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use bit_roles::BitRole;
+
+// Enum with a group whose mask constant shadows `BitRoleImpl::DECLARED_MASK`.
+#[derive(Debug, BitRole, Copy, Clone)]
+enum Role {
+    None = 0,
+    #[bit_role(group = "declared")]
+    One = 1,
+}
+
+fn main() {}
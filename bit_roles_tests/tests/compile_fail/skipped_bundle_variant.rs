@@ -0,0 +1,13 @@
+use bit_roles::BitRole;
+
+// Enum with a bundle referencing a skipped variant.
+#[derive(Debug, BitRole, Copy, Clone)]
+#[bit_role(bundle(Editor = [One, Internal]))]
+enum Role {
+    None = 0,
+    One = 1,
+    #[bit_role(skip)]
+    Internal = 3,
+}
+
+fn main() {}
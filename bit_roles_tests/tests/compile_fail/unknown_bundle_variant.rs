@@ -0,0 +1,11 @@
+use bit_roles::BitRole;
+
+// Enum with a bundle referencing an unknown variant.
+#[derive(Debug, BitRole, Copy, Clone)]
+#[bit_role(bundle(Editor = [One, Two]))]
+enum Role {
+    None = 0,
+    One = 1,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+#![deny(unused_must_use)]
+
+use bit_roles::BitRole;
+
+#[derive(Debug, BitRole, Copy, Clone)]
+enum Role {
+    None = 0,
+    One = 1,
+}
+
+fn main() {
+    let manager = Role::empty();
+
+    // Discarding the result of a query.
+    manager.has_one(Role::One);
+}
@@ -1,4 +1,7 @@
-use bit_roles::BitRole;
+use bit_roles::{
+    BitRole,
+    RoleVariant,
+};
 
 #[test]
 fn can_derive_checked() {
@@ -13,3 +16,191 @@ fn can_derive_checked() {
 
     assert_eq!(roles.get_value(), 0);
 }
+
+#[test]
+fn can_derive_group_masks() {
+    #[allow(dead_code)]
+    #[derive(Debug, BitRole, Copy, Clone)]
+    enum TestRole {
+        None = 0,
+        #[bit_role(group = "content")]
+        One = 1,
+        #[bit_role(group = "content")]
+        Two = 2,
+        #[bit_role(group = "admin")]
+        Four = 4,
+    }
+
+    assert_eq!(
+        TestRole::CONTENT_MASK,
+        TestRole::One as usize | TestRole::Two as usize
+    );
+    assert_eq!(TestRole::ADMIN_MASK, TestRole::Four as usize);
+
+    let roles = TestRole::from_value(7);
+
+    assert_eq!(roles.masked(TestRole::CONTENT_MASK).get_value(), 3);
+}
+
+#[test]
+fn can_derive_declared_mask() {
+    #[allow(dead_code)]
+    #[derive(Debug, BitRole, Copy, Clone)]
+    enum TestRole {
+        None = 0,
+        One = 1,
+        Two = 2,
+        Eight = 8,
+    }
+
+    assert_eq!(
+        TestRole::DECLARED_MASK,
+        TestRole::None as usize
+            | TestRole::One as usize
+            | TestRole::Two as usize
+            | TestRole::Eight as usize
+    );
+    assert_eq!(TestRole::all().get_value(), TestRole::DECLARED_MASK);
+}
+
+#[test]
+fn can_get_the_role_value() {
+    #[allow(dead_code)]
+    #[derive(Debug, BitRole, Copy, Clone)]
+    enum TestRole {
+        None = 0,
+        One = 1,
+        Two = 2,
+    }
+
+    assert_eq!(TestRole::None.value(), 0);
+    assert_eq!(TestRole::Two.value(), 2);
+}
+
+#[test]
+fn can_derive_descriptions() {
+    #[allow(dead_code)]
+    #[derive(Debug, BitRole, Copy, Clone)]
+    enum TestRole {
+        None = 0,
+        #[bit_role(desc = "The first role")]
+        One = 1,
+        #[bit_role(group = "admin", desc = "The second role")]
+        Two = 2,
+        Four = 4,
+    }
+
+    assert_eq!(TestRole::One.describe(), "The first role");
+    assert_eq!(TestRole::Two.describe(), "The second role");
+    assert_eq!(TestRole::Four.describe(), "Four");
+    assert_eq!(TestRole::None.describe(), "None");
+}
+
+#[test]
+fn can_derive_bundles() {
+    #[allow(dead_code)]
+    #[derive(Debug, BitRole, Copy, Clone)]
+    #[bit_role(bundle(Editor = [One, Two], SuperUser = [One, Two, Four], HTTPAdmin = [Four]))]
+    enum TestRole {
+        None = 0,
+        One = 1,
+        Two = 2,
+        Four = 4,
+    }
+
+    assert_eq!(
+        TestRole::editor().get_value(),
+        TestRole::One as usize | TestRole::Two as usize
+    );
+    assert_eq!(TestRole::super_user().get_value(), 7);
+    assert_eq!(TestRole::http_admin().get_value(), TestRole::Four as usize);
+}
+
+#[test]
+fn can_derive_skipped_variants() {
+    #[allow(dead_code)]
+    #[derive(Debug, BitRole, Copy, Clone)]
+    enum TestRole {
+        None = 0,
+        One = 1,
+        #[bit_role(skip)]
+        Internal = 3,
+        Four = 4,
+    }
+
+    assert_eq!(TestRole::DECLARED_MASK, 5);
+    assert!(matches!(
+        TestRole::all_roles().as_slice(),
+        [TestRole::One, TestRole::Four]
+    ));
+    assert!(TestRole::from_name("Internal").is_none());
+    assert!(TestRole::try_from(3).is_err());
+    assert_eq!(TestRole::Internal.name(), "Internal");
+}
+
+#[test]
+fn skipped_variants_do_not_add_roles() {
+    #[allow(dead_code)]
+    #[derive(Debug, BitRole, Copy, Clone)]
+    enum TestRole {
+        None = 0,
+        One = 1,
+        #[bit_role(skip)]
+        Internal = 3,
+    }
+
+    let mut manager = TestRole::from_value(TestRole::One as usize);
+    manager.add_one(TestRole::Internal);
+
+    assert_eq!(manager.get_value(), TestRole::One as usize);
+    assert!(!manager.has_one(TestRole::Internal));
+
+    manager.remove_one(TestRole::Internal);
+
+    assert_eq!(manager.get_value(), TestRole::One as usize);
+}
+
+#[test]
+fn can_derive_const_discriminants() {
+    const ONE: isize = 1;
+    const FOUR: isize = ONE << 2;
+
+    #[allow(dead_code)]
+    #[derive(Debug, BitRole, Copy, Clone)]
+    #[bit_role(bundle(Both = [One, Four]))]
+    enum TestRole {
+        None = ONE - 1,
+        #[bit_role(group = "content")]
+        One = ONE,
+        Two = 2,
+        #[bit_role(group = "content")]
+        Four = FOUR,
+    }
+
+    assert_eq!(TestRole::DECLARED_MASK, 7);
+    assert_eq!(TestRole::CONTENT_MASK, 5);
+    assert_eq!(TestRole::both().get_value(), 5);
+    assert!(matches!(
+        TestRole::all_roles().as_slice(),
+        [TestRole::One, TestRole::Two, TestRole::Four]
+    ));
+    assert!(matches!(TestRole::try_from(4), Ok(TestRole::Four)));
+    assert!(matches!(TestRole::try_from(0), Ok(TestRole::None)));
+}
+
+#[test]
+fn can_derive_strict_zero() {
+    const ZERO: isize = 0;
+    const TWO: isize = 2;
+
+    #[allow(dead_code)]
+    #[derive(Debug, BitRole, Copy, Clone)]
+    #[bit_role(strict_zero)]
+    enum TestRole {
+        None = ZERO,
+        One = 1,
+        Two = TWO,
+    }
+
+    assert_eq!(TestRole::DECLARED_MASK, 3);
+}
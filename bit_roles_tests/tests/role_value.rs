@@ -0,0 +1,34 @@
+use bit_roles::{
+    BitRole,
+    RoleError,
+    RoleValue,
+};
+
+#[allow(dead_code)]
+#[derive(Debug, BitRole, Copy, Clone)]
+enum TestRole {
+    None = 0,
+    One = 1,
+    Two = 2,
+}
+
+#[test]
+fn validate() {
+    let valid: RoleValue<TestRole> = RoleValue::Raw(4);
+    let invalid: RoleValue<TestRole> = RoleValue::Raw(5);
+
+    assert_eq!(valid.validate().unwrap(), 4);
+    assert_eq!(RoleValue::Role(TestRole::Two).validate().unwrap(), 2);
+    assert!(matches!(invalid.validate(), Err(RoleError::InvalidRole(5))));
+}
+
+#[test]
+fn from_roles() {
+    let values = RoleValue::from_roles(&[TestRole::One, TestRole::Two]);
+
+    assert_eq!(
+        values,
+        vec![RoleValue::Role(TestRole::One), RoleValue::Role(TestRole::Two)]
+    );
+    assert!(RoleValue::<TestRole>::from_roles(&[]).is_empty());
+}
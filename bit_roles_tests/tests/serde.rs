@@ -0,0 +1,74 @@
+use bit_roles::{
+    AsInteger,
+    AsNames,
+    BitRole,
+    RoleManager,
+};
+use serde::{
+    de::value::{
+        Error,
+        I64Deserializer,
+    },
+    Deserialize,
+};
+
+#[allow(dead_code)]
+#[derive(Debug, BitRole, Copy, Clone)]
+enum TestRole {
+    None = 0,
+    One = 1,
+    Two = 2,
+    Four = 4,
+}
+
+#[test]
+fn as_integer() {
+    let manager = AsInteger(TestRole::from_value(5));
+    let json = serde_json::to_string(&manager).unwrap();
+
+    assert_eq!(json, "5");
+
+    let AsInteger(decoded) = serde_json::from_str::<AsInteger<TestRole>>(&json).unwrap();
+
+    assert_eq!(decoded, TestRole::from_value(5));
+}
+
+#[test]
+fn as_names() {
+    let manager = AsNames(TestRole::from_value(5));
+    let json = serde_json::to_string(&manager).unwrap();
+
+    assert_eq!(json, r#"["One","Four"]"#);
+
+    let AsNames(decoded) = serde_json::from_str::<AsNames<TestRole>>(&json).unwrap();
+
+    assert_eq!(decoded, TestRole::from_value(5));
+}
+
+#[test]
+fn as_names_rejects_unknown_names() {
+    let result = serde_json::from_str::<AsNames<TestRole>>(r#"["One","Eight"]"#);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn deserialize_from_integer_or_names() {
+    let from_integer = serde_json::from_str::<RoleManager<TestRole>>("3").unwrap();
+    let from_names = serde_json::from_str::<RoleManager<TestRole>>(r#"["One","Two"]"#).unwrap();
+
+    assert_eq!(from_integer, TestRole::from_value(3));
+    assert_eq!(from_names, TestRole::from_value(3));
+    assert!(serde_json::from_str::<RoleManager<TestRole>>(r#"["Eight"]"#).is_err());
+    assert!(serde_json::from_str::<RoleManager<TestRole>>(r#""One""#).is_err());
+}
+
+#[test]
+fn deserialize_from_signed_integer() {
+    let manager = RoleManager::<TestRole>::deserialize(I64Deserializer::<Error>::new(3));
+    let negative = RoleManager::<TestRole>::deserialize(I64Deserializer::<Error>::new(-1));
+
+    assert_eq!(manager.unwrap(), TestRole::from_value(3));
+    assert!(negative.is_err());
+    assert!(serde_json::from_str::<RoleManager<TestRole>>("-1").is_err());
+}
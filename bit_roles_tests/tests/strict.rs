@@ -0,0 +1,50 @@
+#![cfg(feature = "strict")]
+
+use bit_roles::{
+    AsInteger,
+    BitRole,
+    RoleManager,
+};
+
+#[allow(dead_code)]
+#[derive(Debug, BitRole, Copy, Clone)]
+enum TestRole {
+    None = 0,
+    One = 1,
+    Two = 2,
+}
+
+#[test]
+fn from_value_accepts_declared_roles() {
+    let manager = TestRole::from_value(3);
+
+    assert_eq!(manager.get_value(), 3);
+}
+
+#[test]
+#[should_panic(expected = "`value` contains undeclared roles")]
+fn from_value_rejects_undeclared_roles() {
+    TestRole::from_value(0b1001);
+}
+
+#[test]
+fn from_value_unchecked_skips_validation() {
+    let manager = RoleManager::<TestRole>::from_value_unchecked(0b1001);
+
+    assert_eq!(manager.get_value(), 0b1001);
+}
+
+#[test]
+fn as_integer_rejects_undeclared_roles() {
+    let result = serde_json::from_str::<AsInteger<TestRole>>("9");
+
+    assert!(result.is_err());
+    assert!(serde_json::from_str::<AsInteger<TestRole>>("3").is_ok());
+}
+
+#[test]
+fn deserialize_rejects_undeclared_roles() {
+    let result = serde_json::from_str::<RoleManager<TestRole>>("9");
+
+    assert!(result.is_err());
+}
@@ -1,5 +1,8 @@
 use bit_roles::{
+    BitRole,
     BitRoleUnchecked,
+    RoleError,
+    RoleManagerUnchecked,
     RoleValue,
 };
 use std::ops::BitOrAssign;
@@ -102,6 +105,35 @@ fn remove_all() {
     assert_eq!(manager.get_value(), 0);
 }
 
+#[test]
+fn add_iter() {
+    let source = [TestRole::None, TestRole::One, TestRole::Two];
+    let mut manager = TestRole::empty();
+    manager.add_iter(
+        source
+            .iter()
+            .copied()
+            .filter(|role| !matches!(role, TestRole::Two)),
+    );
+
+    assert_eq!(manager.get_value(), TestRole::One as usize);
+}
+
+#[test]
+fn remove_iter() {
+    let source = [TestRole::None, TestRole::One, TestRole::Two];
+    let mut manager = TestRole::empty();
+    manager.add_all(vec![TestRole::One, TestRole::Two]);
+    manager.remove_iter(
+        source
+            .iter()
+            .copied()
+            .filter(|role| !matches!(role, TestRole::Two)),
+    );
+
+    assert_eq!(manager.get_value(), TestRole::Two as usize);
+}
+
 #[test]
 fn has_one() {
     let mut manager = TestRole::empty();
@@ -218,3 +250,175 @@ fn complex_enum() {
     assert!(manager.has_one(Complex::Two(Nested::One)));
     assert_eq!(manager.get_value(), 1);
 }
+
+#[test]
+fn bits() {
+    let manager = TestRole::from_value(0b1010);
+
+    assert_eq!(manager.bits().collect::<Vec<_>>(), vec![1, 3]);
+    assert_eq!(TestRole::empty().bits().count(), 0);
+}
+
+#[test]
+fn try_add_all_atomic() {
+    let mut manager = TestRole::empty();
+    let result = manager.try_add_all_atomic(vec![
+        RoleValue::Role(TestRole::One),
+        RoleValue::Raw(5),
+        RoleValue::Role(TestRole::Two),
+    ]);
+
+    assert!(result.is_err());
+    assert_eq!(manager.get_value(), 0);
+
+    manager
+        .try_add_all_atomic(vec![
+            RoleValue::Role(TestRole::One),
+            RoleValue::Role(TestRole::Two),
+        ])
+        .unwrap();
+
+    assert_eq!(manager.get_value(), 3);
+}
+
+#[test]
+fn try_remove_all_atomic() {
+    let mut manager = TestRole::empty();
+    manager.add_all(vec![TestRole::One, TestRole::Two]);
+
+    let result = manager.try_remove_all_atomic(vec![
+        RoleValue::Role(TestRole::One),
+        RoleValue::Raw(5),
+        RoleValue::Role(TestRole::Two),
+    ]);
+
+    assert!(result.is_err());
+    assert_eq!(manager.get_value(), 3);
+
+    manager
+        .try_remove_all_atomic(vec![
+            RoleValue::Role(TestRole::One),
+            RoleValue::Role(TestRole::Two),
+        ])
+        .unwrap();
+
+    assert_eq!(manager.get_value(), 0);
+}
+
+#[test]
+fn add_all_lossy() {
+    let mut manager = TestRole::empty();
+    let (applied, errors) = manager.add_all_lossy(&[
+        RoleValue::Role(TestRole::One),
+        RoleValue::Raw(3),
+        RoleValue::Role(TestRole::Two),
+        RoleValue::Raw(5),
+    ]);
+
+    assert_eq!(applied, 2);
+    assert!(matches!(
+        errors.as_slice(),
+        [RoleError::InvalidRole(3), RoleError::InvalidRole(5)]
+    ));
+    assert_eq!(manager.get_value(), 3);
+}
+
+#[test]
+fn intersect_with() {
+    let mut manager = TestRole::from_value(7);
+    manager.intersect_with(&TestRole::from_value(TestRole::Two as usize));
+
+    assert_eq!(manager.get_value(), TestRole::Two as usize);
+}
+
+#[test]
+fn union_with() {
+    let mut manager = TestRole::from_value(TestRole::One as usize);
+    manager.union_with(&TestRole::from_value(TestRole::Two as usize));
+
+    assert_eq!(manager.get_value(), 3);
+}
+
+#[test]
+fn subtract() {
+    let mut manager = TestRole::from_value(3);
+    manager.subtract(&TestRole::from_value(TestRole::Two as usize));
+
+    assert_eq!(manager.get_value(), TestRole::One as usize);
+}
+
+#[test]
+fn role_error_is_non_exhaustive() {
+    let mut manager = TestRole::empty();
+    let error = manager.try_add_one(RoleValue::Raw(3)).unwrap_err();
+
+    let value = match error {
+        RoleError::InvalidRole(value) => value,
+        _ => unreachable!("unexpected error"),
+    };
+
+    assert_eq!(value, 3);
+}
+
+#[test]
+fn into_checked() {
+    #[allow(dead_code)]
+    #[derive(Debug, BitRole, Copy, Clone)]
+    enum Role {
+        None = 0,
+        One = 1,
+        Two = 2,
+    }
+
+    let manager = RoleManagerUnchecked::<Role>(3, std::marker::PhantomData)
+        .into_checked()
+        .unwrap();
+
+    assert!(manager.has_all(vec![Role::One, Role::Two]));
+
+    let result =
+        RoleManagerUnchecked::<Role>(1 | 4 | 8, std::marker::PhantomData).into_checked();
+
+    assert!(matches!(result, Err(RoleError::Undeclared(12))));
+}
+
+#[test]
+fn try_from_values_counted() {
+    let (manager, duplicates) = RoleManagerUnchecked::try_from_values_counted(&[
+        RoleValue::Role(TestRole::One),
+        RoleValue::Raw(2),
+        RoleValue::Raw(1),
+        RoleValue::Role(TestRole::Two),
+    ])
+    .unwrap();
+
+    assert_eq!(manager.get_value(), 3);
+    assert_eq!(duplicates, 2);
+
+    let result = RoleManagerUnchecked::<TestRole>::try_from_values_counted(&[
+        RoleValue::Role(TestRole::One),
+        RoleValue::Raw(3),
+    ]);
+
+    assert!(matches!(result, Err(RoleError::InvalidRole(3))));
+}
+
+#[test]
+fn count() {
+    assert_eq!(TestRole::from_value(3).count(), 2);
+    assert_eq!(TestRole::empty().count(), 0);
+}
+
+#[test]
+fn is_empty() {
+    assert!(TestRole::empty().is_empty());
+    assert!(!TestRole::from_value(TestRole::One as usize).is_empty());
+}
+
+#[test]
+fn clear() {
+    let mut manager = TestRole::from_value(3);
+    manager.clear();
+
+    assert_eq!(manager.get_value(), 0);
+}